@@ -1,6 +1,7 @@
 app=COSMIC Web Apps
 loading=Loading...
 open=Open
+add=Add
 number={ $number }
 
 # header
@@ -11,6 +12,40 @@ close=Close
 create-new-webapp=Create new Web App
 icon-selector=Icon selector
 icon-installer=Papirus Icons Installer
+settings=Settings
+recently-deleted=Recently deleted
+    .empty=No recently deleted web apps
+    .purge-all=Empty trash
+    .busy=Updating the trash…
+    .expiry=Web apps are deleted for good { $days } days after they were moved to the trash
+    .confirm-purge=Delete { $name } for good? This can't be undone.
+    .confirm-purge-all=Delete all web apps in the trash for good? This can't be undone.
+    .purge=Delete for good
+    .minutes={ $count } min ago
+    .hours={ $count } h ago
+    .days={ $count } d ago
+    .undo-message={ $name } moved to trash
+    .undo=Undo
+delete=Delete
+    .header=Delete { $name }?
+    .paths=The following files will be moved to the trash:
+    .keep-profile=Keep profile data for later use
+    .busy=Moving to the trash…
+add-account=Add another account
+    .header=Add another account to { $name }
+    .account=Account name, e.g. Work
+    .badge=Mark the icon with a badge
+clear-cache=Clear cache
+clear-site-data=Clear site data
+    .header=Clear the site data of { $name }?
+    .paths=Logins, cookies and everything else sites stored will be removed:
+    .in-use={ $name } is running. Close it before clearing its site data.
+    .busy=Clearing site data…
+duplicate=Duplicate
+    .header=Duplicate { $name }
+    .name={ $name } (copy)
+    .copy-profile=Copy profile data (logins, cookies, extensions)
+    .copying=Copying profile data…
 
 # common.rs
 select-browser=Select Browser
@@ -39,6 +74,68 @@ non-standard-arguments=Non-standard arguments
 navbar=Nav Bar
 isolated-profile=Isolated Profile
 private-mode=Private Mode
+display-mode=Display mode
+    .normal=Normal
+    .fullscreen=Fullscreen
+    .kiosk=Kiosk
+color-scheme=Color scheme
+    .system=Follow system
+    .dark=Dark
+    .light=Light
+zoom=Zoom
+session-policy=Cookies and session
+    .keep=Keep logged in
+    .clear-cookies=Clear cookies on exit
+    .restore=Restore last session
+download-dir=Downloads folder (browser default when empty)
+language=Language
+    .placeholder=Browser default, e.g. de-DE
+display-backend=Display backend
+    .default=Default
+    .wayland=Wayland
+    .x11=X11 (XWayland)
+    .global=Default display backend for all web apps
+    .global-hint=Used by web apps with the default backend. Their launchers are updated as soon as it changes.
+    .updating=Updating web apps…
+env-vars=Environment variables
+    .key=Variable
+    .value=Value
+profile=Profile
+    .isolated=New isolated profile
+    .new-group=New profile group
+    .join-group=Join group { $group }
+    .group-name=Group name
+    .group-shared=Web apps in a group share their profile settings, saving one updates the others.
+browser-profile=Browser profile
+    .none=Web app profile
+profile-migration=If the profile location changes
+    .move=Move profile data
+    .copy=Copy profile data
+    .discard=Start with an empty profile
+    .busy=Moving profile data…
+window-size=Window size
+    .width=Width
+    .height=Height
+window-position=Window position
+    .x=X
+    .y=Y
+extensions=Extensions (.xpi files or unpacked folders, separated by ;)
+user-agent=User agent
+    .default=Browser default
+    .desktop-chrome=Desktop Chrome
+    .desktop-firefox=Desktop Firefox
+    .mobile-safari=Mobile Safari
+    .custom=Custom
+proxy=Proxy
+    .server=socks5://host:1080 or http://host:3128
+    .bypass=Bypass hosts, separated by commas
+firefox-prefs=Firefox preferences
+    .key=Preference name
+    .value=Value
+    .defaults=Defaults for all web apps are read from { $path }
+user-chrome-css=Custom userChrome.css file
+theme-colors=COSMIC theme colors
+user-content-css=CSS applied to the site (userContent.css)
 
 # iconpicker.rs
 icon-name-to-find=Icon name to find
@@ -56,3 +153,7 @@ warning=You don't meet requirements
     .app-url=  - You must provide valid URL starting with http:// or https://
     .app-icon=  - You must select an Icon for your launcher
     .app-browser=  - Please select a browser. Make sure at least one is installed system-wide or via Flatpak
+    .window-size=  - Window width and height must both be whole numbers greater than 0
+    .window-position=  - Window position must be two whole numbers
+    .save-failed=  - The web app could not be saved, check the log for details
+    .env-var-name=  - Environment variable names may only contain letters, digits and underscores and can't start with a digit
@@ -0,0 +1,163 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::common::{home_dir, Browser, BrowserType};
+
+/// A profile of the browser itself, as opposed to one created for a web app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrowserProfile {
+    /// `Profile N` directory for Chromium, profile name for Firefox.
+    pub id: String,
+    pub name: String,
+}
+
+/// Profiles the user already has in the given browser.
+pub fn browser_profiles(browser: &Browser) -> Vec<BrowserProfile> {
+    let Some(root) = profiles_root(browser) else {
+        return Vec::new();
+    };
+
+    if browser._type.is_firefox() {
+        firefox_profiles(&root)
+    } else {
+        chromium_profiles(&root)
+    }
+}
+
+fn profiles_root(browser: &Browser) -> Option<PathBuf> {
+    let exec = Path::new(&browser.exec).file_name()?.to_str()?;
+    let home = home_dir();
+
+    let root = match browser._type {
+        BrowserType::Firefox if exec.starts_with("librewolf") => home.join(".librewolf"),
+        BrowserType::Firefox if exec.starts_with("waterfox") => home.join(".waterfox"),
+        BrowserType::Firefox => home.join(".mozilla/firefox"),
+        BrowserType::FirefoxFlatpak => home.join(".var/app/org.mozilla.firefox/.mozilla/firefox"),
+        BrowserType::Librewolf => home.join(".var/app/io.gitlab.librewolf-community/.librewolf"),
+        BrowserType::WaterfoxFlatpak => home.join(".var/app/net.waterfox.waterfox/.waterfox"),
+        BrowserType::Chromium => {
+            let config_dir = chromium_config_dir(exec)?;
+
+            if exec.contains('.') {
+                // flatpak app id
+                home.join(".var/app")
+                    .join(exec)
+                    .join("config")
+                    .join(config_dir)
+            } else {
+                home.join(".config").join(config_dir)
+            }
+        }
+        _ => return None,
+    };
+
+    Some(root)
+}
+
+fn chromium_config_dir(exec: &str) -> Option<&'static str> {
+    let config_dir = match exec {
+        "chromium" | "chromium-browser" | "chromium-bin" => "chromium",
+        "org.chromium.Chromium" | "com.github.Eloston.UngoogledChromium" => "chromium",
+        "google-chrome-stable" | "com.google.Chrome" => "google-chrome",
+        "google-chrome-beta" => "google-chrome-beta",
+        "brave" | "brave-browser" | "brave-bin" | "com.brave.Browser" => {
+            "BraveSoftware/Brave-Browser"
+        }
+        "vivaldi-stable" | "com.vivaldi.Vivaldi" => "vivaldi",
+        "vivaldi-snapshot" => "vivaldi-snapshot",
+        "microsoft-edge-stable" | "com.microsoft.Edge" => "microsoft-edge",
+        "microsoft-edge-beta" => "microsoft-edge-beta",
+        "microsoft-edge-dev" => "microsoft-edge-dev",
+        "flashpeak-slimjet" => "slimjet",
+        "yandex-browser" | "ru.yandex.Browser" => "yandex-browser",
+        "naver-whale-stable" => "naver-whale",
+        _ => return None,
+    };
+
+    Some(config_dir)
+}
+
+/// Reads the `[ProfileN]` sections of `profiles.ini`.
+fn firefox_profiles(root: &Path) -> Vec<BrowserProfile> {
+    let Ok(content) = fs::read_to_string(root.join("profiles.ini")) else {
+        return Vec::new();
+    };
+
+    let mut profiles = Vec::new();
+    let mut in_profile = false;
+
+    for line in content.lines() {
+        let line = line.trim();
+
+        if line.starts_with('[') {
+            in_profile = line.starts_with("[Profile");
+        } else if let Some(name) = line.strip_prefix("Name=") {
+            if in_profile {
+                profiles.push(BrowserProfile {
+                    id: name.to_string(),
+                    name: name.to_string(),
+                });
+            }
+        }
+    }
+
+    profiles
+}
+
+/// Reads `profile.info_cache` of Chromium's `Local State`.
+fn chromium_profiles(root: &Path) -> Vec<BrowserProfile> {
+    let Ok(content) = fs::read_to_string(root.join("Local State")) else {
+        return Vec::new();
+    };
+
+    let Ok(local_state) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return Vec::new();
+    };
+
+    let Some(info_cache) = local_state
+        .pointer("/profile/info_cache")
+        .and_then(|cache| cache.as_object())
+    else {
+        return Vec::new();
+    };
+
+    let mut profiles: Vec<BrowserProfile> = info_cache
+        .iter()
+        .map(|(dir, info)| BrowserProfile {
+            id: dir.clone(),
+            name: info
+                .get("name")
+                .and_then(|name| name.as_str())
+                .unwrap_or(dir)
+                .to_string(),
+        })
+        .collect();
+
+    profiles.sort_by(|a, b| a.id.cmp(&b.id));
+    profiles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::supported_browsers::{flatpak_browsers, native_browsers};
+
+    #[test]
+    fn every_chromium_browser_has_a_config_dir() {
+        for browser in native_browsers().into_iter().chain(flatpak_browsers()) {
+            if browser._type != BrowserType::Chromium {
+                continue;
+            }
+
+            let exec = Path::new(&browser.exec).file_name().unwrap();
+
+            assert!(
+                chromium_config_dir(exec.to_str().unwrap()).is_some(),
+                "no config dir for {}",
+                browser.exec
+            );
+        }
+    }
+}
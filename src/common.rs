@@ -1,10 +1,11 @@
 #![allow(clippy::too_many_arguments)]
 
 use std::{
+    collections::HashMap,
     ffi::OsStr,
     fs::{self, copy, create_dir_all, remove_dir_all, remove_file, File},
     io::{self, BufRead, Cursor, Read, Write},
-    path::PathBuf,
+    path::{Path, PathBuf},
     str::FromStr,
     sync::Mutex,
 };
@@ -19,6 +20,7 @@ use image::GenericImageView;
 use rand::{thread_rng, Rng};
 use reqwest::Client;
 use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
 use svg::node::element::Image;
 use svg::Document;
 use url::Url;
@@ -26,10 +28,12 @@ use usvg::fontdb;
 use walkdir::WalkDir;
 
 use crate::{
+    config::Config,
     fl,
     icon_cache::IconCache,
     pages,
     supported_browsers::{flatpak_browsers, native_browsers},
+    theme::cosmic_user_chrome_css,
 };
 
 lazy_static::lazy_static! {
@@ -85,21 +89,57 @@ pub fn my_icons_location() -> PathBuf {
     icons_location().join("MyIcons")
 }
 
+pub fn config_location() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| home_dir().join(".config"))
+        .join("io.github.elevenhsoft.WebApps")
+}
+
+/// Prefs from this file are merged into the user.js of every Firefox web app.
+pub fn firefox_defaults_location() -> PathBuf {
+    config_location().join("user.js")
+}
+
+/// Names `env` accepts as variables, anything else would be run as the command.
+pub fn env_var_name_valid(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 pub fn webapplauncher_is_valid(
     webbrowser: &Browser,
     icon: &str,
     codename: &str,
     name: &str,
     url: &str,
+    window_size: Option<(u32, u32)>,
+    env_vars: &[(String, String)],
 ) -> bool {
+    if window_size.is_some_and(|(width, height)| width == 0 || height == 0) {
+        return false;
+    }
+
+    if env_vars.iter().any(|(key, _)| !env_var_name_valid(key)) {
+        return false;
+    }
+
     let installed = get_webapps();
 
-    for app in installed.iter().flatten() {
+    // the launcher being edited keeps its codename and may keep its name
+    for app in installed
+        .iter()
+        .flatten()
+        .filter(|app| app.codename != codename)
+    {
         if !url_valid(url)
             || !webbrowser.is_installed()
             || (name.is_empty() || app.name == name)
             || icon.is_empty()
-            || (codename.is_empty() || app.codename == codename)
+            || codename.is_empty()
             || url.is_empty()
         {
             return false;
@@ -125,6 +165,27 @@ pub struct WebAppLauncher {
     pub isolate_profile: bool,
     pub navbar: bool,
     pub is_incognito: bool,
+    pub display_mode: DisplayMode,
+    pub window_size: Option<(u32, u32)>,
+    pub window_position: Option<(i32, i32)>,
+    pub extensions: Vec<String>,
+    pub user_agent: String,
+    pub proxy: String,
+    pub proxy_bypass: String,
+    pub firefox_prefs: Vec<(String, String)>,
+    pub user_chrome_css: String,
+    pub theme_colors: bool,
+    pub user_content_css: String,
+    pub color_scheme: ColorScheme,
+    pub display_backend: DisplayBackend,
+    pub env_vars: Vec<(String, String)>,
+    pub profile_group: String,
+    pub browser_profile: String,
+    pub account_of: String,
+    pub zoom: u32,
+    pub language: String,
+    pub download_dir: String,
+    pub session_policy: SessionPolicy,
 }
 
 impl WebAppLauncher {
@@ -139,13 +200,29 @@ impl WebAppLauncher {
         isolated: bool,
         navbar: bool,
         privatewindow: bool,
+        display_mode: DisplayMode,
+        window_size: Option<(u32, u32)>,
+        window_position: Option<(i32, i32)>,
+        extensions: Vec<String>,
+        user_agent: String,
+        proxy: String,
+        proxy_bypass: String,
+        firefox_prefs: Vec<(String, String)>,
+        user_chrome_css: String,
+        theme_colors: bool,
+        user_content_css: String,
+        color_scheme: ColorScheme,
+        display_backend: DisplayBackend,
+        env_vars: Vec<(String, String)>,
+        profile_group: String,
+        browser_profile: String,
+        account_of: String,
+        zoom: u32,
+        language: String,
+        download_dir: String,
+        session_policy: SessionPolicy,
     ) -> Self {
-        let codename = if let Some(codename) = codename {
-            codename
-        } else {
-            let random_code: u16 = thread_rng().gen_range(1000..10000);
-            format!("{}{}", name.replace(' ', ""), random_code)
-        };
+        let codename = codename.unwrap_or_else(|| new_codename(&name));
         let filename = format!("webapp-{}.desktop", codename);
         let path = desktop_filepath(&filename);
         let web_browser = browser;
@@ -154,7 +231,15 @@ impl WebAppLauncher {
         let isolate_profile = isolated;
         let is_incognito = privatewindow;
 
-        let is_valid = webapplauncher_is_valid(&web_browser, &icon, &codename, &name, &url);
+        let is_valid = webapplauncher_is_valid(
+            &web_browser,
+            &icon,
+            &codename,
+            &name,
+            &url,
+            window_size,
+            &env_vars,
+        );
 
         Self {
             path,
@@ -171,6 +256,27 @@ impl WebAppLauncher {
             isolate_profile,
             navbar,
             is_incognito,
+            display_mode,
+            window_size,
+            window_position,
+            extensions,
+            user_agent,
+            proxy,
+            proxy_bypass,
+            firefox_prefs,
+            user_chrome_css,
+            theme_colors,
+            user_content_css,
+            color_scheme,
+            display_backend,
+            env_vars,
+            profile_group,
+            browser_profile,
+            account_of,
+            zoom,
+            language,
+            download_dir,
+            session_policy,
         }
     }
 
@@ -180,7 +286,7 @@ impl WebAppLauncher {
         let mut name = String::new();
         let mut icon = String::new();
         let mut is_valid = false;
-        let mut exec = String::new();
+        let mut exec_line = String::new();
         let mut args = Vec::new();
         let mut category = String::new();
         let mut url = String::new();
@@ -188,6 +294,27 @@ impl WebAppLauncher {
         let mut isolate_profile = false;
         let mut navbar = false;
         let mut is_incognito = false;
+        let mut display_mode = DisplayMode::Normal;
+        let mut window_size = None;
+        let mut window_position = None;
+        let mut extensions = Vec::new();
+        let mut user_agent = String::new();
+        let mut proxy = String::new();
+        let mut proxy_bypass = String::new();
+        let mut firefox_prefs = Vec::new();
+        let mut user_chrome_css = String::new();
+        let mut theme_colors = false;
+        let mut user_content_css = String::new();
+        let mut color_scheme = ColorScheme::System;
+        let mut display_backend = DisplayBackend::Default;
+        let mut env_vars = Vec::new();
+        let mut profile_group = String::new();
+        let mut browser_profile = String::new();
+        let mut account_of = String::new();
+        let mut zoom = DEFAULT_ZOOM;
+        let mut language = String::new();
+        let mut download_dir = String::new();
+        let mut session_policy = SessionPolicy::Keep;
 
         let reader = io::BufReader::new(file);
 
@@ -203,47 +330,139 @@ impl WebAppLauncher {
                         is_webapp = true;
                     };
 
-                    if line.contains("Name=") {
-                        name = line.replace("Name=", "");
+                    if let Some(value) = line.strip_prefix("Name=") {
+                        name = value.to_string();
+                    };
+
+                    if let Some(value) = line.strip_prefix("Icon=") {
+                        icon = value.to_string();
+                    };
+
+                    if let Some(value) = line.strip_prefix("Exec=") {
+                        exec_line = value.to_string();
+                    };
+
+                    if let Some(value) = line.strip_prefix("Categories=") {
+                        category = value.replace("GTK;", "").replace(';', "");
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-Browser=") {
+                        browser_name = value.to_string();
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-URL=") {
+                        url = value.to_string();
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-CustomParameters=") {
+                        custom_parameters = value.to_string();
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-Isolated=") {
+                        isolate_profile = value == "true"
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-Navbar=") {
+                        navbar = value == "true"
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-PrivateWindow=") {
+                        is_incognito = value == "true"
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-DisplayMode=") {
+                        display_mode = DisplayMode::from_key(value)
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-WindowSize=") {
+                        window_size = parse_pair(value, 'x')
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-WindowPosition=") {
+                        window_position = parse_pair(value, ',')
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-Extensions=") {
+                        extensions = split_list(value)
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-UserAgent=") {
+                        user_agent = value.to_string()
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-Proxy=") {
+                        proxy = value.to_string()
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-ProxyBypass=") {
+                        proxy_bypass = value.to_string()
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-FirefoxPrefs=") {
+                        firefox_prefs = split_list(value)
+                            .iter()
+                            .filter_map(|pref| pref.split_once('='))
+                            .map(|(key, value)| (key.to_string(), value.to_string()))
+                            .collect()
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-UserChromeCss=") {
+                        user_chrome_css = value.to_string()
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-ThemeColors=") {
+                        theme_colors = value == "true"
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-UserContentCss=") {
+                        user_content_css = unescape_value(value)
+                    };
+
+                    if let Some(value) = line.strip_prefix("X-WebApp-ColorScheme=") {
+                        color_scheme = ColorScheme::from_key(value)
                     };
 
-                    if line.contains("Icon=") {
-                        icon = line.replace("Icon=", "");
+                    if let Some(value) = line.strip_prefix("X-WebApp-DisplayBackend=") {
+                        display_backend = DisplayBackend::from_key(value)
                     };
 
-                    if line.contains("Exec=") {
-                        exec = line.replace("Exec=", "");
+                    if let Some(value) = line.strip_prefix("X-WebApp-EnvVars=") {
+                        env_vars = split_list(value)
+                            .iter()
+                            .map(|var| unescape_value(var))
+                            .filter_map(|var| {
+                                var.split_once('=')
+                                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                            })
+                            .collect()
                     };
 
-                    if line.contains("Categories=") {
-                        category = line
-                            .replace("Categories=", "")
-                            .replace("GTK;", "")
-                            .replace(';', "");
+                    if let Some(value) = line.strip_prefix("X-WebApp-ProfileGroup=") {
+                        profile_group = value.to_string()
                     };
 
-                    if line.contains("X-WebApp-Browser=") {
-                        browser_name = line.replace("X-WebApp-Browser=", "");
+                    if let Some(value) = line.strip_prefix("X-WebApp-BrowserProfile=") {
+                        browser_profile = value.to_string()
                     };
 
-                    if line.contains("X-WebApp-URL=") {
-                        url = line.replace("X-WebApp-URL=", "");
+                    if let Some(value) = line.strip_prefix("X-WebApp-AccountOf=") {
+                        account_of = value.to_string()
                     };
 
-                    if line.contains("X-WebApp-CustomParameters=") {
-                        custom_parameters = line.replace("X-WebApp-CustomParameters=", "");
+                    if let Some(value) = line.strip_prefix("X-WebApp-Zoom=") {
+                        zoom = value.parse().unwrap_or(DEFAULT_ZOOM)
                     };
 
-                    if line.contains("X-WebApp-Isolated=") {
-                        isolate_profile = line.replace("X-WebApp-Isolated=", "") == "true"
+                    if let Some(value) = line.strip_prefix("X-WebApp-Language=") {
+                        language = value.to_string()
                     };
 
-                    if line.contains("X-WebApp-Navbar=") {
-                        navbar = line.replace("X-WebApp-Navbar=", "") == "true"
+                    if let Some(value) = line.strip_prefix("X-WebApp-DownloadDir=") {
+                        download_dir = value.to_string()
                     };
 
-                    if line.contains("X-WebApp-PrivateWindow=") {
-                        is_incognito = line.replace("X-WebApp-PrivateWindow=", "") == "true"
+                    if let Some(value) = line.strip_prefix("X-WebApp-SessionPolicy=") {
+                        session_policy = SessionPolicy::from_key(value)
                     };
                 }
                 Err(e) => eprintln!("Error reading line: {}", e),
@@ -258,12 +477,15 @@ impl WebAppLauncher {
 
         match web_browser {
             Some(web_browser) => {
-                exec.split(' ').enumerate().for_each(|(n, arg)| {
+                exec_line.split(' ').enumerate().for_each(|(n, arg)| {
                     if n > 0 && !arg.is_empty() {
                         args.push(arg.to_string())
                     }
                 });
 
+                // the exec builders append to the bare browser executable
+                let exec = web_browser.exec.clone();
+
                 Ok(WebAppLauncher {
                     path,
                     codename,
@@ -279,46 +501,511 @@ impl WebAppLauncher {
                     isolate_profile,
                     navbar,
                     is_incognito,
+                    display_mode,
+                    window_size,
+                    window_position,
+                    extensions,
+                    user_agent,
+                    proxy,
+                    proxy_bypass,
+                    firefox_prefs,
+                    user_chrome_css,
+                    theme_colors,
+                    user_content_css,
+                    color_scheme,
+                    display_backend,
+                    env_vars,
+                    profile_group,
+                    browser_profile,
+                    account_of,
+                    zoom,
+                    language,
+                    download_dir,
+                    session_policy,
                 })
             }
             None => Err(anyhow!("Cannot read web app launcher.")),
         }
     }
 
+    fn user_prefs(&self) -> Vec<(String, String)> {
+        let mut prefs = Vec::new();
+
+        if !self.extensions.is_empty() {
+            // enable extensions dropped into the profile without asking on first run
+            prefs.push(("extensions.autoDisableScopes".to_string(), "0".to_string()));
+        }
+
+        if !self.user_agent.is_empty() {
+            prefs.push((
+                "general.useragent.override".to_string(),
+                quote_string(&self.user_agent),
+            ));
+        }
+
+        prefs.extend(firefox_proxy_prefs(&self.proxy, &self.proxy_bypass));
+
+        // scales the page and the browser ui together, like the chromium flag does
+        if self.zoom != DEFAULT_ZOOM {
+            prefs.push((
+                "layout.css.devPixelsPerPx".to_string(),
+                quote_string(&zoom_factor(self.zoom)),
+            ));
+        }
+
+        // ui language and the languages sites are asked for
+        if !self.language.is_empty() {
+            prefs.push((
+                "intl.locale.requested".to_string(),
+                quote_string(&self.language),
+            ));
+            prefs.push((
+                "intl.accept_languages".to_string(),
+                quote_string(&self.language),
+            ));
+        }
+
+        // folderList 2 saves to browser.download.dir instead of the system folder
+        if !self.download_dir.is_empty() {
+            prefs.push((
+                "browser.download.dir".to_string(),
+                quote_string(&self.download_dir),
+            ));
+            prefs.push(("browser.download.folderList".to_string(), "2".to_string()));
+            prefs.push((
+                "browser.download.useDownloadDir".to_string(),
+                "true".to_string(),
+            ));
+        }
+
+        // the bundled user.js keeps everything, these override it
+        match self.session_policy {
+            SessionPolicy::Keep => {}
+            SessionPolicy::ClearCookies => {
+                for pref in [
+                    "privacy.sanitize.sanitizeOnShutdown",
+                    "privacy.clearOnShutdown.cookies",
+                    "privacy.clearOnShutdown.sessions",
+                    "privacy.clearOnShutdown.offlineApps",
+                    "privacy.clearOnShutdown_v2.cookiesAndStorage",
+                ] {
+                    prefs.push((pref.to_string(), "true".to_string()));
+                }
+            }
+            SessionPolicy::Restore => {
+                prefs.push(("browser.startup.page".to_string(), "3".to_string()));
+            }
+        }
+
+        // content-override: 0 dark, 1 light, 2 follow the system, 3 follow the browser theme
+        match self.color_scheme {
+            ColorScheme::System => {
+                prefs.push((
+                    "layout.css.prefers-color-scheme.content-override".to_string(),
+                    "2".to_string(),
+                ));
+            }
+            ColorScheme::Dark => {
+                prefs.push((
+                    "layout.css.prefers-color-scheme.content-override".to_string(),
+                    "0".to_string(),
+                ));
+                prefs.push(("ui.systemUsesDarkTheme".to_string(), "1".to_string()));
+            }
+            ColorScheme::Light => {
+                prefs.push((
+                    "layout.css.prefers-color-scheme.content-override".to_string(),
+                    "1".to_string(),
+                ));
+                prefs.push(("ui.systemUsesDarkTheme".to_string(), "0".to_string()));
+            }
+        }
+
+        prefs.extend(
+            self.firefox_prefs
+                .iter()
+                .map(|(key, value)| (key.clone(), pref_value(value))),
+        );
+
+        prefs
+    }
+
+    // bundled prefs < option defaults < user defaults < prefs generated from app options < app overrides
     fn create_firefox_userjs(&self, path: PathBuf) -> bool {
-        let content = include_bytes!("../data/runtime/firefox/profile/user.js");
+        let content = include_str!("../data/runtime/firefox/profile/user.js");
+        let mut prefs = parse_user_prefs(content);
+
+        // firefox keeps the last user.js value in prefs.js, so a cleared option has to be
+        // written back with its default instead of just dropping the line
+        merge_prefs(&mut prefs, option_default_prefs());
+
+        if let Ok(defaults) = fs::read_to_string(firefox_defaults_location()) {
+            merge_prefs(&mut prefs, parse_user_prefs(&defaults));
+        }
+
+        merge_prefs(&mut prefs, self.user_prefs());
 
-        let mut file = File::create(&path)
-            .unwrap_or_else(|_| panic!("failed to create user.js in {:?}", path));
+        remove_stale_prefs(&path.with_file_name("prefs.js"), &prefs);
 
-        file.write_all(content).is_ok()
+        let content: String = prefs
+            .iter()
+            .map(|(key, value)| format!("user_pref(\"{}\", {});\n", key, value))
+            .collect();
+
+        write_atomic(&path, content.as_bytes()).is_ok()
     }
 
     fn create_user_chrome_css(&self, path: PathBuf, create_navbar: bool) -> bool {
         let user_chrome_css =
             include_bytes!("../data/runtime/firefox/profile/chrome/userChrome.css");
 
-        let mut file = File::create(&path)
-            .unwrap_or_else(|_| panic!("cant create userChrome.css in {:?}", path));
+        let mut content = Vec::new();
+
+        if !create_navbar {
+            content.extend_from_slice(user_chrome_css);
+        }
+
+        if self.theme_colors {
+            content.extend_from_slice(cosmic_user_chrome_css().as_bytes());
+        }
+
+        // custom css goes last so it wins over the bundled and generated rules
+        if !self.user_chrome_css.is_empty() {
+            match fs::read(&self.user_chrome_css) {
+                Ok(custom) => content.extend_from_slice(&custom),
+                Err(e) => tracing::error!("cant read {}: {}", self.user_chrome_css, e),
+            }
+        }
+
+        write_atomic(&path, &content).is_ok()
+    }
+
+    fn create_user_content_css(&self, path: PathBuf) -> bool {
+        write_atomic(&path, self.user_content_css.as_bytes()).is_ok()
+    }
+
+    /// Sets the initial window position in the xulstore.json of an own Firefox profile.
+    /// Firefox stores where the window was left in the same place, so without a
+    /// position the file is left alone.
+    fn create_firefox_xulstore(&self, profile_path: &Path) -> bool {
+        let Some((x, y)) = self.window_position else {
+            return true;
+        };
+
+        let path = profile_path.join("xulstore.json");
+
+        let mut store = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .filter(|store| store.is_object())
+            .unwrap_or_else(|| serde_json::json!({}));
+
+        let browser = "chrome://browser/content/browser.xhtml";
+
+        if !store[browser].is_object() {
+            store[browser] = serde_json::json!({});
+        }
+        if !store[browser]["main-window"].is_object() {
+            store[browser]["main-window"] = serde_json::json!({});
+        }
+
+        store[browser]["main-window"]["screenX"] = serde_json::json!(x.to_string());
+        store[browser]["main-window"]["screenY"] = serde_json::json!(y.to_string());
+
+        write_atomic(&path, store.to_string().as_bytes()).is_ok()
+    }
+
+    /// Sets the download folder and cookie policy in the Preferences of an own
+    /// Chromium profile, keeping everything Chromium already stored there.
+    fn create_chromium_preferences(&self, profile_path: &Path) -> bool {
+        let default_dir = profile_path.join("Default");
+        let path = default_dir.join("Preferences");
+
+        let mut prefs = fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+            .filter(|prefs| prefs.is_object())
+            .unwrap_or_else(|| serde_json::json!({}));
+        let previous = prefs.clone();
+
+        if !self.download_dir.is_empty() {
+            if !prefs["download"].is_object() {
+                prefs["download"] = serde_json::json!({});
+            }
+
+            prefs["download"]["default_directory"] = serde_json::json!(self.download_dir);
+            prefs["download"]["prompt_for_download"] = serde_json::json!(false);
+        } else if let Some(download) = prefs["download"].as_object_mut() {
+            download.remove("default_directory");
+            download.remove("prompt_for_download");
+        }
+
+        // 4 keeps cookies for the session only, anything else was set by the user
+        let cookies = "/profile/default_content_setting_values/cookies";
+
+        if self.session_policy == SessionPolicy::ClearCookies {
+            if !prefs["profile"].is_object() {
+                prefs["profile"] = serde_json::json!({});
+            }
+            if !prefs["profile"]["default_content_setting_values"].is_object() {
+                prefs["profile"]["default_content_setting_values"] = serde_json::json!({});
+            }
+
+            prefs["profile"]["default_content_setting_values"]["cookies"] = serde_json::json!(4);
+        } else if prefs.pointer(cookies) == Some(&serde_json::json!(4)) {
+            if let Some(settings) =
+                prefs["profile"]["default_content_setting_values"].as_object_mut()
+            {
+                settings.remove("cookies");
+            }
+        }
+
+        if prefs == previous {
+            return true;
+        }
+
+        create_dir_all(&default_dir).is_ok()
+            && write_atomic(&path, prefs.to_string().as_bytes()).is_ok()
+    }
+
+    /// Codename of the app all accounts of this site are grouped under.
+    pub fn account_root(&self) -> &str {
+        if self.account_of.is_empty() {
+            &self.codename
+        } else {
+            &self.account_of
+        }
+    }
+
+    /// Sibling launcher for another account on the same site. It gets its own
+    /// isolated profile and is named after the site with the account as suffix.
+    pub fn add_account(&self, account: &str, badge: bool) -> Result<WebAppLauncher> {
+        let site_name = if self.account_of.is_empty() {
+            self.name.as_str()
+        } else {
+            self.name
+                .rsplit_once(" (")
+                .map_or(self.name.as_str(), |(name, _)| name)
+        };
+
+        let mut launcher = self.duplicate(format!("{} ({})", site_name, account), false)?;
+        launcher.account_of = self.account_root().to_string();
+        launcher.isolate_profile = true;
+        launcher.profile_group.clear();
+        launcher.browser_profile.clear();
+
+        if badge && launcher.is_valid {
+            let save_path = my_icons_location().join(format!("{}.svg", launcher.codename));
+
+            match badge_icon(Path::new(&self.icon), account, &save_path) {
+                Ok(()) => launcher.icon = save_path.to_str().unwrap().to_string(),
+                Err(e) => tracing::error!("cant create icon badge: {}", e),
+            }
+        }
+
+        Ok(launcher)
+    }
+
+    /// Copy of this web app under a new codename. With `copy_profile` the copy gets
+    /// its own profile, starting from the data of this app's profile.
+    pub fn duplicate(&self, name: String, copy_profile: bool) -> Result<WebAppLauncher> {
+        let mut launcher = self.clone();
+
+        launcher.codename = new_codename(&name);
+        launcher.path = desktop_filepath(&format!("webapp-{}.desktop", launcher.codename));
+        launcher.is_valid = webapplauncher_is_valid(
+            &launcher.web_browser,
+            &launcher.icon,
+            &launcher.codename,
+            &name,
+            &launcher.url,
+            launcher.window_size,
+            &launcher.env_vars,
+        );
+        launcher.name = name;
+
+        if copy_profile {
+            launcher.profile_group.clear();
+
+            if let (Some(source), Some(target)) = (self.profile_path(), launcher.profile_path()) {
+                if launcher.is_valid && source.exists() {
+                    clone_profile_dir(&source, &target)?;
+                }
+            }
+        }
+
+        Ok(launcher)
+    }
 
-        if create_navbar {
-            file.write_all(b"").is_ok()
+    /// Name of the profile directory, shared by all members of a profile group.
+    ///
+    /// Browsers of the same engine keep their profiles in one directory, so groups are
+    /// keyed by the browser executable as well.
+    fn profile_name(&self) -> String {
+        if self.profile_group.is_empty() {
+            self.codename.clone()
         } else {
-            file.write_all(user_chrome_css).is_ok()
+            let browser = Path::new(&self.web_browser.exec)
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default();
+
+            format!("group-{}-{}", browser, self.profile_group)
         }
     }
 
-    fn exec_firefox(&self, fork: &str) -> String {
+    /// Profile directory of this web app, `None` when it runs with the browser's own profile.
+    pub fn profile_path(&self) -> Option<PathBuf> {
         let mut profile_dir = home_dir();
-        if fork == "firefox" {
-            profile_dir.push(".var/app/org.mozilla.firefox/data/ice/firefox");
-        } else if fork == "librewolf" {
-            profile_dir.push(".var/app/io.gitlab.librewolf-community/data/ice/librewolf");
-        } else if fork == "waterfox" {
-            profile_dir.push(".var/app/net.waterfox.waterfox/data/ice/waterfox");
+
+        if !self.browser_profile.is_empty()
+            && (self.web_browser._type.is_firefox()
+                || self.web_browser._type == BrowserType::Chromium)
+        {
+            return None;
+        }
+
+        match self.web_browser._type {
+            BrowserType::Firefox | BrowserType::FirefoxFlatpak => {
+                profile_dir.push(".var/app/org.mozilla.firefox/data/ice/firefox")
+            }
+            BrowserType::Librewolf => {
+                profile_dir.push(".var/app/io.gitlab.librewolf-community/data/ice/librewolf")
+            }
+            BrowserType::WaterfoxFlatpak => {
+                profile_dir.push(".var/app/net.waterfox.waterfox/data/ice/waterfox")
+            }
+            BrowserType::Qutebrowser => profile_dir.push(".local/share/ice/profiles"),
+            BrowserType::Chromium | BrowserType::Falkon if self.isolate_profile => {
+                profile_dir.push(".local/share/ice/profiles")
+            }
+            _ => return None,
+        };
+
+        Some(profile_dir.join(self.profile_name()))
+    }
+
+    /// Cache and service worker storage of the profile, following the engine's layout.
+    fn profile_cache_paths(&self, profile: &Path) -> Vec<PathBuf> {
+        let mut paths = Vec::new();
+
+        if self.web_browser._type.is_firefox() {
+            paths.extend(FIREFOX_CACHE.iter().map(|path| profile.join(path)));
+
+            // Cache API storage of service workers lives next to the other site storage
+            if let Ok(origins) = fs::read_dir(profile.join("storage/default")) {
+                paths.extend(origins.flatten().map(|origin| origin.path().join("cache")));
+            }
+        } else {
+            match self.web_browser._type {
+                BrowserType::Chromium => {
+                    paths.extend(
+                        CHROMIUM_CACHE
+                            .iter()
+                            .map(|path| profile.join("Default").join(path)),
+                    );
+                    paths.extend(CHROMIUM_SHADER_CACHE.iter().map(|path| profile.join(path)));
+                }
+                BrowserType::Qutebrowser => {
+                    let webengine = profile.join("data/webengine");
+
+                    paths.push(profile.join("cache"));
+                    paths.extend(CHROMIUM_CACHE.iter().map(|path| webengine.join(path)));
+                }
+                _ => {}
+            }
+        }
+
+        paths
+    }
+
+    /// Everything sites stored in the profile, caches included.
+    fn profile_site_data_paths(&self, profile: &Path) -> Vec<PathBuf> {
+        let mut paths = self.profile_cache_paths(profile);
+
+        if self.web_browser._type.is_firefox() {
+            paths.extend(FIREFOX_SITE_DATA.iter().map(|path| profile.join(path)));
+        } else {
+            match self.web_browser._type {
+                BrowserType::Chromium => paths.extend(
+                    CHROMIUM_SITE_DATA
+                        .iter()
+                        .map(|path| profile.join("Default").join(path)),
+                ),
+                BrowserType::Qutebrowser => paths.extend(
+                    CHROMIUM_SITE_DATA
+                        .iter()
+                        .map(|path| profile.join("data/webengine").join(path)),
+                ),
+                _ => {}
+            }
+        }
+
+        paths
+    }
+
+    /// Whether the cache and site data of this app's profile can be cleared.
+    pub fn can_clean_profile(&self) -> bool {
+        self.profile_path()
+            .is_some_and(|profile| !self.profile_cache_paths(&profile).is_empty())
+    }
+
+    pub fn clear_cache(&self) -> Result<()> {
+        if let Some(profile) = self.profile_path() {
+            remove_paths(&self.profile_cache_paths(&profile))?;
+            tracing::info!("Cleared profile cache.");
+        }
+
+        Ok(())
+    }
+
+    /// Existing files and directories [`Self::clear_site_data`] would remove.
+    pub fn site_data_paths(&self) -> Vec<PathBuf> {
+        self.profile_path()
+            .map(|profile| self.profile_site_data_paths(&profile))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|path| path.exists())
+            .collect()
+    }
+
+    /// Whether a browser has the profile open. The lock files are dangling symlinks
+    /// on Linux, so `exists` can't be used.
+    pub fn profile_in_use(&self) -> bool {
+        self.profile_path().is_some_and(|profile| {
+            PROFILE_LOCK_FILES
+                .iter()
+                .any(|file| fs::symlink_metadata(profile.join(file)).is_ok())
+        })
+    }
+
+    pub fn clear_site_data(&self) -> Result<()> {
+        if self.profile_in_use() {
+            return Err(anyhow!("{} is running", self.name));
+        }
+
+        if let Some(profile) = self.profile_path() {
+            remove_paths(&self.profile_site_data_paths(&profile))?;
+            tracing::info!("Cleared profile site data.");
+        }
+
+        Ok(())
+    }
+
+    fn exec_firefox(&self) -> String {
+        let Some(profile_path) = self.profile_path() else {
+            // the browser's own profile is used as is and may already be open
+            let exec_string = format!(
+                "{} --class WebApp-{} --name WebApp-{} -P {} ",
+                self.exec,
+                self.codename,
+                self.codename,
+                quote_exec_arg(&self.browser_profile)
+            );
+
+            return self.firefox_window_args(exec_string);
         };
 
-        let profile_path = profile_dir.join(&self.codename);
         let user_js_path = profile_path.join("user.js");
         let mut user_chrome_css = profile_path.join("chrome");
 
@@ -327,22 +1014,46 @@ impl WebAppLauncher {
         create_dir_all(&user_chrome_css)
             .unwrap_or_else(|_| panic!("cant create chrome dir in {:?}", &user_chrome_css));
 
+        let user_content_css = user_chrome_css.join("userContent.css");
         user_chrome_css = user_chrome_css.join("userChrome.css");
 
         self.create_firefox_userjs(user_js_path);
         self.create_user_chrome_css(user_chrome_css, self.navbar);
+        self.create_user_content_css(user_content_css);
+        self.create_firefox_xulstore(&profile_path);
+        install_firefox_extensions(&self.extensions, &profile_path);
 
         let profile_path = profile_path.to_str().unwrap();
 
         let mut exec_string = format!(
-            "{} --class WebApp-{} --name WebApp-{} --profile {} --no-remote ",
+            "{} --class WebApp-{} --name WebApp-{} --profile {} ",
             self.exec, self.codename, self.codename, profile_path
         );
 
+        // A profile can only be opened by one Firefox instance, so members of a
+        // group open their window in the instance that is already running.
+        if self.profile_group.is_empty() {
+            exec_string.push_str("--no-remote ");
+        }
+
+        self.firefox_window_args(exec_string)
+    }
+
+    fn firefox_window_args(&self, mut exec_string: String) -> String {
         if self.is_incognito {
             exec_string.push_str("--private-window ");
         }
 
+        // Firefox has no switch to start in plain fullscreen, kiosk is the closest match.
+        if self.display_mode != DisplayMode::Normal {
+            exec_string.push_str("--kiosk ");
+        }
+
+        // The window position is set in xulstore.json, there is no switch for it.
+        if let Some((width, height)) = self.window_size {
+            exec_string.push_str(&format!("--width {} --height {} ", width, height));
+        }
+
         if !self.custom_parameters.is_empty() {
             exec_string.push_str(&format!("{} ", self.custom_parameters));
         }
@@ -358,16 +1069,20 @@ impl WebAppLauncher {
             self.exec, self.url, self.codename, self.codename
         );
 
-        if self.isolate_profile {
-            let mut profile_dir = PathBuf::new();
-
-            let mut xdg_data_home = home_dir();
-            xdg_data_home.push(".local/share");
-            let ice_dir = xdg_data_home.join("ice");
-            profile_dir.push(ice_dir.join("profiles").join(&self.codename));
+        if let Some(profile_path) = self.profile_path() {
+            self.create_chromium_preferences(&profile_path);
 
-            let profile_path = profile_dir.to_str().unwrap();
+            let profile_path = profile_path.to_str().unwrap();
             exec_string.push_str(&format!("--user-data-dir={} ", profile_path));
+        } else if !self.browser_profile.is_empty() {
+            exec_string.push_str(&format!(
+                "{} ",
+                quote_exec_arg(&format!("--profile-directory={}", self.browser_profile))
+            ));
+        }
+
+        if self.session_policy == SessionPolicy::Restore {
+            exec_string.push_str("--restore-last-session ");
         }
 
         if self.is_incognito {
@@ -378,122 +1093,1170 @@ impl WebAppLauncher {
             }
         }
 
+        match self.display_mode {
+            DisplayMode::Normal => {}
+            DisplayMode::Fullscreen => exec_string.push_str("--start-fullscreen "),
+            DisplayMode::Kiosk => exec_string.push_str("--kiosk "),
+        }
+
+        if let Some((width, height)) = self.window_size {
+            exec_string.push_str(&format!("--window-size={},{} ", width, height));
+        }
+
+        if let Some((x, y)) = self.window_position {
+            exec_string.push_str(&format!("--window-position={},{} ", x, y));
+        }
+
+        let unpacked: Vec<&str> = self
+            .extensions
+            .iter()
+            .filter(|path| Path::new(path).is_dir())
+            .map(|path| path.as_str())
+            .collect();
+
+        if !unpacked.is_empty() {
+            exec_string.push_str(&format!(
+                "{} ",
+                quote_exec_arg(&format!("--load-extension={}", unpacked.join(",")))
+            ));
+        }
+
+        if !self.user_agent.is_empty() {
+            exec_string.push_str(&format!(
+                "{} ",
+                quote_exec_arg(&format!("--user-agent={}", self.user_agent))
+            ));
+        }
+
+        if !self.language.is_empty() {
+            exec_string.push_str(&format!(
+                "{} ",
+                quote_exec_arg(&format!("--lang={}", self.language))
+            ));
+        }
+
+        if self.zoom != DEFAULT_ZOOM {
+            exec_string.push_str(&format!(
+                "--force-device-scale-factor={} ",
+                zoom_factor(self.zoom)
+            ));
+        }
+
+        match self.effective_display_backend() {
+            DisplayBackend::Default => {}
+            DisplayBackend::Wayland => exec_string.push_str("--ozone-platform-hint=auto "),
+            DisplayBackend::X11 => exec_string.push_str("--ozone-platform=x11 "),
+        }
+
+        match self.color_scheme {
+            ColorScheme::System => {}
+            ColorScheme::Dark => exec_string
+                .push_str("--force-dark-mode --blink-settings=forceDarkModeEnabled=true "),
+            ColorScheme::Light => exec_string.push_str("--blink-settings=preferredColorScheme=1 "),
+        }
+
+        if !self.proxy.is_empty() {
+            exec_string.push_str(&format!(
+                "{} ",
+                quote_exec_arg(&format!("--proxy-server={}", self.proxy))
+            ));
+
+            if !self.proxy_bypass.is_empty() {
+                let bypass_list = self.proxy_bypass.replace(',', ";").replace(' ', "");
+
+                exec_string.push_str(&format!(
+                    "{} ",
+                    quote_exec_arg(&format!("--proxy-bypass-list={}", bypass_list))
+                ));
+            }
+        }
+
         if !self.custom_parameters.is_empty() {
             exec_string.push_str(&format!("{} ", self.custom_parameters));
         }
 
-        exec_string
+        exec_string
+    }
+
+    fn exec_falkon(&self) -> String {
+        let mut exec_string = String::new();
+
+        if let Some(profile_path) = self.profile_path() {
+            let profile_path = profile_path.to_str().unwrap();
+
+            exec_string = format!(
+                "{} --portable --wmclass WebApp-{} --profile {} ",
+                self.exec, self.codename, profile_path
+            );
+        }
+
+        if self.is_incognito {
+            exec_string.push_str("--private-browsing ");
+        }
+
+        if self.display_mode != DisplayMode::Normal {
+            exec_string.push_str("--fullscreen ");
+        }
+
+        if !self.custom_parameters.is_empty() {
+            exec_string.push_str(&format!("{} ", self.custom_parameters));
+        }
+
+        exec_string.push_str(&format!("--no-remote --current-tab {}", self.url));
+
+        exec_string
+    }
+
+    fn create_qutebrowser_config(&self, path: PathBuf) -> bool {
+        let mut content = String::from("config.load_autoconfig(False)\n");
+
+        if !self.navbar || self.display_mode == DisplayMode::Kiosk {
+            content.push_str("c.tabs.show = \"never\"\n");
+            content.push_str("c.statusbar.show = \"never\"\n");
+        }
+
+        if !self.user_agent.is_empty() {
+            content.push_str(&format!(
+                "c.content.headers.user_agent = {}\n",
+                quote_string(&self.user_agent)
+            ));
+        }
+
+        match self.color_scheme {
+            ColorScheme::System => {}
+            ColorScheme::Dark => {
+                content.push_str("c.colors.webpage.preferred_color_scheme = \"dark\"\n");
+                content.push_str("c.colors.webpage.darkmode.enabled = True\n");
+            }
+            ColorScheme::Light => {
+                content.push_str("c.colors.webpage.preferred_color_scheme = \"light\"\n");
+            }
+        }
+
+        if !self.language.is_empty() {
+            content.push_str(&format!(
+                "c.content.headers.accept_language = {}\n",
+                quote_string(&self.language)
+            ));
+        }
+
+        if !self.download_dir.is_empty() {
+            content.push_str(&format!(
+                "c.downloads.location.directory = {}\n",
+                quote_string(&self.download_dir)
+            ));
+            content.push_str("c.downloads.location.prompt = False\n");
+        }
+
+        match self.session_policy {
+            SessionPolicy::Keep => {}
+            SessionPolicy::ClearCookies => content.push_str("c.content.cookies.store = False\n"),
+            SessionPolicy::Restore => content.push_str("c.auto_save.session = True\n"),
+        }
+
+        if self.zoom != DEFAULT_ZOOM {
+            content.push_str(&format!("c.zoom.default = \"{}%\"\n", self.zoom));
+        }
+
+        // qutebrowser has no per proxy bypass list
+        if !self.proxy.is_empty() {
+            content.push_str(&format!(
+                "c.content.proxy = {}\n",
+                quote_string(&self.proxy)
+            ));
+        }
+
+        write_atomic(&path, content.as_bytes()).is_ok()
+    }
+
+    fn exec_qutebrowser(&self) -> String {
+        let basedir = self
+            .profile_path()
+            .expect("qutebrowser web apps always use their own profile");
+        let config_dir = basedir.join("config");
+
+        create_dir_all(&config_dir)
+            .unwrap_or_else(|_| panic!("cant create config dir in {:?}", &config_dir));
+
+        self.create_qutebrowser_config(config_dir.join("config.py"));
+
+        let basedir = basedir.to_str().unwrap();
+
+        let mut exec_string = format!(
+            "{} --basedir {} --qt-wmclass WebApp-{} --desktop-file-name WebApp-{} ",
+            self.exec, basedir, self.codename, self.codename
+        );
+
+        if self.is_incognito {
+            exec_string.push_str("--target private-window ");
+        }
+
+        if !self.custom_parameters.is_empty() {
+            exec_string.push_str(&format!("{} ", self.custom_parameters));
+        }
+
+        exec_string.push_str(&self.url);
+
+        if self.display_mode != DisplayMode::Normal {
+            exec_string.push_str(" :fullscreen");
+        }
+
+        exec_string
+    }
+
+    /// Per-app backend, falling back to the global default from the settings page.
+    fn effective_display_backend(&self) -> DisplayBackend {
+        match self.display_backend {
+            DisplayBackend::Default => Config::load().display_backend,
+            backend => backend,
+        }
+    }
+
+    fn backend_env(&self) -> Vec<(String, String)> {
+        let mut env = Vec::new();
+        let backend = self.effective_display_backend();
+
+        if self.web_browser._type.is_firefox() {
+            match backend {
+                DisplayBackend::Default => {}
+                DisplayBackend::Wayland => {
+                    env.push(("MOZ_ENABLE_WAYLAND".to_string(), "1".to_string()))
+                }
+                DisplayBackend::X11 => {
+                    env.push(("MOZ_ENABLE_WAYLAND".to_string(), "0".to_string()))
+                }
+            }
+        }
+
+        if matches!(
+            self.web_browser._type,
+            BrowserType::Falkon | BrowserType::Qutebrowser
+        ) {
+            match backend {
+                DisplayBackend::Default => {}
+                DisplayBackend::Wayland => {
+                    env.push(("QT_QPA_PLATFORM".to_string(), "wayland".to_string()))
+                }
+                DisplayBackend::X11 => env.push(("QT_QPA_PLATFORM".to_string(), "xcb".to_string())),
+            }
+        }
+
+        env
+    }
+
+    fn exec_string(&self) -> String {
+        let mut env = self.backend_env();
+        env.extend(self.env_vars.iter().cloned());
+
+        if env.is_empty() {
+            return self.browser_exec_string();
+        }
+
+        let mut exec_string = String::from("env ");
+
+        for (key, value) in env {
+            exec_string.push_str(&format!(
+                "{} ",
+                quote_exec_arg(&format!("{}={}", key, value))
+            ));
+        }
+
+        exec_string.push_str(&self.browser_exec_string());
+
+        exec_string
+    }
+
+    fn browser_exec_string(&self) -> String {
+        match self.web_browser._type {
+            BrowserType::Firefox => self.exec_firefox(),
+            BrowserType::FirefoxFlatpak => self.exec_firefox(),
+            BrowserType::Librewolf => self.exec_firefox(),
+            BrowserType::WaterfoxFlatpak => self.exec_firefox(),
+            BrowserType::Chromium => self.exec_chromium(),
+            BrowserType::Falkon => self.exec_falkon(),
+            BrowserType::Qutebrowser => self.exec_qutebrowser(),
+            _ => String::new(),
+        }
+    }
+
+    pub fn create(&self) -> Result<()> {
+        let mut output = Vec::new();
+
+        writeln!(output, "[Desktop Entry]")?;
+        writeln!(output, "Version=1.0")?;
+        writeln!(output, "Name={}", self.name)?;
+        writeln!(output, "Comment=Web App")?;
+        writeln!(output, "Exec={}", self.exec_string())?;
+        writeln!(output, "Terminal=false")?;
+        writeln!(output, "Type=Application")?;
+        writeln!(output, "Icon={}", self.icon)?;
+        writeln!(output, "Categories=GTK;{};", self.category)?;
+        writeln!(output, "MimeType=text/html;text/xml;application/xhtml_xml;")?;
+        writeln!(output, "StartupWMClass=WebApp-{}", self.codename)?;
+        writeln!(output, "StartupNotify=true")?;
+        writeln!(output, "X-MultipleArgs=false")?;
+        writeln!(output, "X-WebApp-Browser={}", self.web_browser.name)?;
+        writeln!(output, "X-WebApp-URL={}", self.url)?;
+        writeln!(output, "X-WebApp-Navbar={}", self.navbar)?;
+        writeln!(output, "X-WebApp-PrivateWindow={}", self.is_incognito)?;
+        writeln!(output, "X-WebApp-Isolated={}", self.isolate_profile)?;
+        writeln!(output, "X-WebApp-DisplayMode={}", self.display_mode.key())?;
+        writeln!(
+            output,
+            "X-WebApp-WindowSize={}",
+            self.window_size
+                .map(|(width, height)| format!("{}x{}", width, height))
+                .unwrap_or_default()
+        )?;
+        writeln!(
+            output,
+            "X-WebApp-WindowPosition={}",
+            self.window_position
+                .map(|(x, y)| format!("{},{}", x, y))
+                .unwrap_or_default()
+        )?;
+        writeln!(
+            output,
+            "X-WebApp-Extensions={}",
+            join_list(&self.extensions)
+        )?;
+        writeln!(output, "X-WebApp-UserAgent={}", self.user_agent)?;
+        writeln!(output, "X-WebApp-Proxy={}", self.proxy)?;
+        writeln!(output, "X-WebApp-ProxyBypass={}", self.proxy_bypass)?;
+        writeln!(
+            output,
+            "X-WebApp-FirefoxPrefs={}",
+            join_list(
+                &self
+                    .firefox_prefs
+                    .iter()
+                    .map(|(key, value)| format!("{}={}", key, value))
+                    .collect::<Vec<String>>()
+            )
+        )?;
+        writeln!(output, "X-WebApp-UserChromeCss={}", self.user_chrome_css)?;
+        writeln!(output, "X-WebApp-ThemeColors={}", self.theme_colors)?;
+        writeln!(
+            output,
+            "X-WebApp-UserContentCss={}",
+            escape_value(&self.user_content_css)
+        )?;
+        writeln!(output, "X-WebApp-ColorScheme={}", self.color_scheme.key())?;
+        writeln!(
+            output,
+            "X-WebApp-DisplayBackend={}",
+            self.display_backend.key()
+        )?;
+        writeln!(
+            output,
+            "X-WebApp-EnvVars={}",
+            join_list(
+                &self
+                    .env_vars
+                    .iter()
+                    .map(|(key, value)| escape_value(&format!("{}={}", key, value)))
+                    .collect::<Vec<String>>()
+            )
+        )?;
+        writeln!(output, "X-WebApp-ProfileGroup={}", self.profile_group)?;
+        writeln!(output, "X-WebApp-BrowserProfile={}", self.browser_profile)?;
+        writeln!(output, "X-WebApp-AccountOf={}", self.account_of)?;
+        writeln!(output, "X-WebApp-Zoom={}", self.zoom)?;
+        writeln!(output, "X-WebApp-Language={}", self.language)?;
+        writeln!(output, "X-WebApp-DownloadDir={}", self.download_dir)?;
+        writeln!(
+            output,
+            "X-WebApp-SessionPolicy={}",
+            self.session_policy.key()
+        )?;
+        writeln!(
+            output,
+            "X-WebApp-CustomParameters={}",
+            self.custom_parameters
+        )?;
+
+        write_atomic(&self.path, &output)
+    }
+
+    /// Copies the profile of `previous`, the app before editing, to this app's profile
+    /// location. Returns whether the old profile should be deleted once the edited
+    /// launcher has been saved, which is only the case after a discard or a move
+    /// that had to fall back to copying.
+    pub fn migrate_profile(
+        &self,
+        previous: &WebAppLauncher,
+        migration: ProfileMigration,
+    ) -> Result<bool> {
+        let Some(source) = previous.profile_path().filter(|path| path.exists()) else {
+            return Ok(false);
+        };
+
+        if self.profile_path().as_ref() == Some(&source) {
+            return Ok(false);
+        }
+
+        if migration == ProfileMigration::Discard {
+            return Ok(true);
+        }
+
+        let Some(target) = self.profile_path() else {
+            return Ok(false);
+        };
+
+        // an existing profile, e.g. of a joined group, is never overwritten
+        if !self
+            .web_browser
+            ._type
+            .same_engine(&previous.web_browser._type)
+            || target.exists()
+        {
+            return Ok(false);
+        }
+
+        if migration == ProfileMigration::Move {
+            match move_profile_dir(&source, &target) {
+                Ok(()) => return Ok(false),
+                // e.g. the new location is on another file system
+                Err(e) => tracing::warn!("cant rename profile, copying it instead: {}", e),
+            }
+        }
+
+        clone_profile_dir(&source, &target)?;
+
+        Ok(migration == ProfileMigration::Move)
+    }
+
+    /// Profile directory removed together with this app, `None` when there is none
+    /// or other members of its group still use it.
+    pub fn removable_profile(&self) -> Option<PathBuf> {
+        let profile_path = self.profile_path().filter(|path| path.exists())?;
+
+        let shared = !self.profile_group.is_empty()
+            && get_webapps().iter().flatten().any(|app| {
+                app.codename != self.codename && app.profile_path().as_ref() == Some(&profile_path)
+            });
+
+        (!shared).then_some(profile_path)
+    }
+
+    /// Other web apps sharing this app's profile directory through a profile group.
+    pub fn group_members(&self) -> Vec<WebAppLauncher> {
+        if self.profile_group.is_empty() {
+            return Vec::new();
+        }
+
+        let Some(profile_path) = self.profile_path() else {
+            return Vec::new();
+        };
+
+        get_webapps()
+            .into_iter()
+            .flatten()
+            .filter(|app| {
+                app.codename != self.codename && app.profile_path().as_ref() == Some(&profile_path)
+            })
+            .collect()
+    }
+
+    /// Takes over the settings that end up in the profile files rather than in the
+    /// launcher, so every member of a group writes the shared profile the same way.
+    pub fn share_profile_settings(&mut self, from: &WebAppLauncher) {
+        match self.web_browser._type {
+            _ if self.web_browser._type.is_firefox() => {
+                self.navbar = from.navbar;
+                self.user_agent.clone_from(&from.user_agent);
+                self.proxy.clone_from(&from.proxy);
+                self.proxy_bypass.clone_from(&from.proxy_bypass);
+                self.firefox_prefs.clone_from(&from.firefox_prefs);
+                self.user_chrome_css.clone_from(&from.user_chrome_css);
+                self.theme_colors = from.theme_colors;
+                self.user_content_css.clone_from(&from.user_content_css);
+                self.color_scheme = from.color_scheme;
+                self.extensions.clone_from(&from.extensions);
+                self.zoom = from.zoom;
+                self.language.clone_from(&from.language);
+            }
+            BrowserType::Qutebrowser => {
+                self.navbar = from.navbar;
+                self.user_agent.clone_from(&from.user_agent);
+                self.proxy.clone_from(&from.proxy);
+                self.color_scheme = from.color_scheme;
+                self.zoom = from.zoom;
+                self.language.clone_from(&from.language);
+            }
+            BrowserType::Chromium => {}
+            _ => return,
+        }
+
+        self.download_dir.clone_from(&from.download_dir);
+        self.session_policy = from.session_policy;
+    }
+
+    /// Rewrites the other members of the group with the profile settings of this app,
+    /// otherwise the next one to be saved would silently undo them.
+    pub fn sync_profile_group(&self) -> Result<()> {
+        for mut member in self.group_members() {
+            member.share_profile_settings(self);
+            member.create()?;
+        }
+
+        Ok(())
+    }
+
+    /// Icon saved to `MyIcons` for this app, `None` when other web apps use it too.
+    pub fn removable_icon(&self) -> Option<PathBuf> {
+        let icon = PathBuf::from(&self.icon);
+
+        if !icon.starts_with(my_icons_location()) || !icon.exists() {
+            return None;
+        }
+
+        let shared = get_webapps()
+            .iter()
+            .flatten()
+            .any(|app| app.codename != self.codename && app.icon == self.icon);
+
+        (!shared).then_some(icon)
+    }
+
+    pub fn delete_profile(&self) -> Result<()> {
+        if let Some(profile_path) = self.removable_profile() {
+            remove_dir_all(profile_path)?;
+            tracing::info!("Removed profile directory.");
+        }
+
+        Ok(())
+    }
+}
+
+fn new_codename(name: &str) -> String {
+    loop {
+        let random_code: u16 = thread_rng().gen_range(1000..10000);
+        let codename = format!("{}{}", name.replace(' ', ""), random_code);
+
+        if !desktop_filepath(&format!("webapp-{}.desktop", codename)).exists() {
+            return codename;
+        }
+    }
+}
+
+/// Writes to a temporary file next to `path` and renames it into place, so readers
+/// only ever see the old or the complete new content.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| anyhow!("{} has no parent directory", path.display()))?;
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow!("{} has no file name", path.display()))?;
+    let temp_path = parent.join(format!(".{}.tmp", file_name.to_string_lossy()));
+
+    let written = File::create(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(contents)?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temp_path, path));
+
+    if let Err(e) = written {
+        // a full disk would otherwise be left with a truncated temp file
+        let _ = remove_file(&temp_path);
+        return Err(e.into());
+    }
+
+    // persist the rename itself
+    File::open(parent)?.sync_all()?;
+
+    Ok(())
+}
+
+/// Profile files which store absolute paths pointing into their own profile.
+const PROFILE_PATH_FILES: [&str; 8] = [
+    "prefs.js",
+    "extensions.json",
+    "compatibility.ini",
+    "pkcs11.txt",
+    "times.json",
+    "addons.json",
+    "Preferences",
+    "Local State",
+];
+
+/// Lock files that only exist while a browser runs. Firefox also keeps a
+/// `.parentlock` on Linux, but that one stays behind after it exits.
+const PROFILE_LOCK_FILES: [&str; 4] = [
+    "lock",
+    "SingletonLock",
+    "SingletonCookie",
+    "SingletonSocket",
+];
+
+/// Copies a whole profile. A partial copy is removed again, otherwise it would
+/// be taken for an existing profile the next time.
+fn clone_profile_dir(source: &Path, target: &Path) -> Result<()> {
+    if let Err(e) = copy_profile_dir(source, target, source, target) {
+        if let Err(e) = remove_dir_all(target) {
+            tracing::error!("cant remove partial copy {:?}: {}", target, e);
+        }
+
+        return Err(e);
+    }
+
+    Ok(())
+}
+
+/// Renames a profile and updates the absolute paths stored in it.
+fn move_profile_dir(source: &Path, target: &Path) -> Result<()> {
+    if let Some(parent) = target.parent() {
+        create_dir_all(parent)?;
+    }
+
+    fs::rename(source, target)?;
+
+    for entry in WalkDir::new(target).into_iter().filter_map(|e| e.ok()) {
+        let file_name = entry.file_name().to_string_lossy();
+
+        if !entry.file_type().is_file() || !PROFILE_PATH_FILES.contains(&file_name.as_ref()) {
+            continue;
+        }
+
+        if let Ok(content) = fs::read_to_string(entry.path()) {
+            fs::write(
+                entry.path(),
+                content.replace(
+                    source.to_string_lossy().as_ref(),
+                    target.to_string_lossy().as_ref(),
+                ),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn copy_profile_dir(dir: &Path, target_dir: &Path, source: &Path, target: &Path) -> Result<()> {
+    create_dir_all(target_dir)?;
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        let to = target_dir.join(file_name.as_ref());
+
+        if file_type.is_symlink() || PROFILE_LOCK_FILES.contains(&file_name.as_ref()) {
+            continue;
+        }
+
+        if file_type.is_dir() {
+            copy_profile_dir(&entry.path(), &to, source, target)?;
+        } else if PROFILE_PATH_FILES.contains(&file_name.as_ref()) {
+            match fs::read_to_string(entry.path()) {
+                Ok(content) => fs::write(
+                    &to,
+                    content.replace(
+                        source.to_string_lossy().as_ref(),
+                        target.to_string_lossy().as_ref(),
+                    ),
+                )?,
+                Err(_) => {
+                    copy(entry.path(), &to)?;
+                }
+            }
+        } else {
+            copy(entry.path(), &to)?;
+        }
+    }
+
+    Ok(())
+}
+
+const FIREFOX_CACHE: [&str; 6] = [
+    "cache2",
+    "startupCache",
+    "thumbnails",
+    "shader-cache",
+    "OfflineCache",
+    "serviceworker.txt",
+];
+
+const FIREFOX_SITE_DATA: [&str; 9] = [
+    "cookies.sqlite",
+    "cookies.sqlite-wal",
+    "cookies.sqlite-shm",
+    "webappsstore.sqlite",
+    "webappsstore.sqlite-wal",
+    "webappsstore.sqlite-shm",
+    "storage",
+    "sessionstore.jsonlz4",
+    "sessionstore-backups",
+];
+
+/// Relative to the `Default` profile of Chromium or the QtWebEngine data dir.
+const CHROMIUM_CACHE: [&str; 6] = [
+    "Cache",
+    "Code Cache",
+    "GPUCache",
+    "DawnCache",
+    "Service Worker/CacheStorage",
+    "Service Worker/ScriptCache",
+];
+
+const CHROMIUM_SHADER_CACHE: [&str; 3] = ["GrShaderCache", "ShaderCache", "GraphiteDawnCache"];
+
+const CHROMIUM_SITE_DATA: [&str; 12] = [
+    "Cookies",
+    "Cookies-journal",
+    "Network/Cookies",
+    "Network/Cookies-journal",
+    "Local Storage",
+    "Session Storage",
+    "IndexedDB",
+    "Service Worker",
+    "databases",
+    "File System",
+    "WebStorage",
+    "Sessions",
+];
+
+fn remove_paths(paths: &[PathBuf]) -> Result<()> {
+    for path in paths {
+        if path.is_dir() {
+            remove_dir_all(path)?;
+        } else if path.exists() {
+            remove_file(path)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => dir_size(&entry.path()),
+            Ok(file_type) if file_type.is_file() => entry.metadata().map_or(0, |m| m.len()),
+            _ => 0,
+        })
+        .sum()
+}
+
+pub fn format_size(bytes: u64) -> String {
+    let units = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+
+    while size >= 1024. && unit < units.len() - 1 {
+        size /= 1024.;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, units[unit])
+    } else {
+        format!("{:.1} {}", size, units[unit])
+    }
+}
+
+/// Runs file system work that can take a while, like copying a profile, off the ui thread.
+pub async fn run_blocking<T: Send + 'static>(
+    work: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    tokio::task::spawn_blocking(work).await?
+}
+
+/// Disk usage of every web app profile, keyed by profile directory.
+pub async fn profile_sizes() -> HashMap<PathBuf, u64> {
+    let sizes = run_blocking(|| {
+        Ok(get_webapps()
+            .into_iter()
+            .flatten()
+            .filter_map(|app| app.profile_path())
+            .map(|path| {
+                let size = dir_size(&path);
+                (path, size)
+            })
+            .collect())
+    });
+
+    sizes.await.unwrap_or_else(|e| {
+        tracing::error!("cant measure profiles: {}", e);
+        HashMap::new()
+    })
+}
+
+pub fn parse_pair<T: FromStr>(value: &str, separator: char) -> Option<(T, T)> {
+    let (first, second) = value.split_once(separator)?;
+
+    Some((first.trim().parse().ok()?, second.trim().parse().ok()?))
+}
+
+/// Quotes a single `Exec` argument following the Desktop Entry specification.
+pub fn quote_exec_arg(arg: &str) -> String {
+    let arg = arg.replace('%', "%%");
+
+    if !arg.chars().any(|c| " \t\n\"'\\><~|&;$*?#()`".contains(c)) {
+        return arg;
+    }
+
+    let mut quoted = String::from("\"");
+
+    for c in arg.chars() {
+        match c {
+            // escaped once for the quoting rule and once more for the string value
+            '"' | '`' | '$' => {
+                quoted.push_str("\\\\");
+                quoted.push(c);
+            }
+            '\\' => quoted.push_str("\\\\\\\\"),
+            _ => quoted.push(c),
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Double quoted string literal usable in both user.js and qutebrowser's config.py.
+pub fn quote_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Translates a proxy URL like `socks5://127.0.0.1:1080` into Firefox `network.proxy.*` prefs.
+pub fn firefox_proxy_prefs(proxy: &str, bypass: &str) -> Vec<(String, String)> {
+    let mut prefs = Vec::new();
+
+    let Ok(url) = Url::parse(proxy) else {
+        return prefs;
+    };
+
+    let Some(host) = url.host_str() else {
+        return prefs;
+    };
+
+    let host = quote_string(host);
+
+    match url.scheme() {
+        "http" | "https" => {
+            let port = url.port_or_known_default().unwrap_or(8080).to_string();
+
+            prefs.push(("network.proxy.http".to_string(), host.clone()));
+            prefs.push(("network.proxy.http_port".to_string(), port.clone()));
+            prefs.push(("network.proxy.ssl".to_string(), host));
+            prefs.push(("network.proxy.ssl_port".to_string(), port));
+        }
+        scheme if scheme.starts_with("socks") => {
+            let port = url.port().unwrap_or(1080).to_string();
+            let version = if scheme == "socks4" { "4" } else { "5" };
+
+            prefs.push(("network.proxy.socks".to_string(), host));
+            prefs.push(("network.proxy.socks_port".to_string(), port));
+            prefs.push((
+                "network.proxy.socks_version".to_string(),
+                version.to_string(),
+            ));
+            prefs.push((
+                "network.proxy.socks_remote_dns".to_string(),
+                "true".to_string(),
+            ));
+        }
+        _ => return prefs,
+    }
+
+    prefs.push(("network.proxy.type".to_string(), "1".to_string()));
+
+    if !bypass.is_empty() {
+        prefs.push((
+            "network.proxy.no_proxies_on".to_string(),
+            quote_string(bypass),
+        ));
+    }
+
+    prefs
+}
+
+/// Firefox defaults of every pref generated from app options.
+fn option_default_prefs() -> Vec<(String, String)> {
+    [
+        ("extensions.autoDisableScopes", "15"),
+        ("network.proxy.type", "5"),
+        ("network.proxy.http", "\"\""),
+        ("network.proxy.http_port", "0"),
+        ("network.proxy.ssl", "\"\""),
+        ("network.proxy.ssl_port", "0"),
+        ("network.proxy.socks", "\"\""),
+        ("network.proxy.socks_port", "0"),
+        ("network.proxy.socks_version", "5"),
+        ("network.proxy.socks_remote_dns", "false"),
+        ("network.proxy.no_proxies_on", "\"\""),
+        ("layout.css.devPixelsPerPx", "\"-1.0\""),
+        ("intl.locale.requested", "\"\""),
+        ("browser.download.dir", "\"\""),
+        ("browser.download.folderList", "1"),
+        ("browser.download.useDownloadDir", "true"),
+        ("browser.startup.page", "1"),
+        ("privacy.sanitize.sanitizeOnShutdown", "false"),
+        ("privacy.clearOnShutdown.cookies", "true"),
+        ("privacy.clearOnShutdown.sessions", "true"),
+        ("privacy.clearOnShutdown.offlineApps", "false"),
+        ("privacy.clearOnShutdown_v2.cookiesAndStorage", "true"),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), value.to_string()))
+    .collect()
+}
+
+/// Prefs generated from app options whose default can't be written as a literal.
+/// Firefox uses the user agent override whenever it exists, even when empty.
+const UNSET_ONLY_PREFS: [&str; 3] = [
+    "general.useragent.override",
+    "intl.accept_languages",
+    "ui.systemUsesDarkTheme",
+];
+
+/// Drops values firefox saved in `prefs.js` for [`UNSET_ONLY_PREFS`] that are no longer set.
+fn remove_stale_prefs(prefs_js: &Path, prefs: &[(String, String)]) {
+    let Ok(content) = fs::read_to_string(prefs_js) else {
+        return;
+    };
+
+    let stale: Vec<String> = UNSET_ONLY_PREFS
+        .iter()
+        .filter(|key| !prefs.iter().any(|(existing, _)| existing == *key))
+        .map(|key| format!("user_pref(\"{}\",", key))
+        .collect();
+
+    let kept: Vec<&str> = content
+        .lines()
+        .filter(|line| !stale.iter().any(|pref| line.trim().starts_with(pref)))
+        .collect();
+
+    if kept.len() != content.lines().count() {
+        let content = kept.join("\n") + "\n";
+
+        if let Err(e) = write_atomic(prefs_js, content.as_bytes()) {
+            tracing::error!("cant update {:?}: {}", prefs_js, e);
+        }
+    }
+}
+
+/// Reads `user_pref("key", value);` lines, keeping values as raw JavaScript literals.
+pub fn parse_user_prefs(content: &str) -> Vec<(String, String)> {
+    let mut prefs = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        let Some(pref) = line
+            .strip_prefix("user_pref(")
+            .or_else(|| line.strip_prefix("pref("))
+            .and_then(|pref| pref.strip_suffix(");"))
+        else {
+            continue;
+        };
+
+        let Some((key, value)) = pref.split_once(',') else {
+            continue;
+        };
+
+        let key = key.trim().trim_matches('"');
+
+        if !key.is_empty() {
+            prefs.push((key.to_string(), value.trim().to_string()));
+        }
+    }
+
+    prefs
+}
+
+/// Overrides prefs with the same key in place and appends the new ones.
+pub fn merge_prefs(prefs: &mut Vec<(String, String)>, overrides: Vec<(String, String)>) {
+    for (key, value) in overrides {
+        match prefs.iter_mut().find(|(existing, _)| *existing == key) {
+            Some(pref) => pref.1 = value,
+            None => prefs.push((key, value)),
+        }
     }
+}
 
-    fn exec_falkon(&self) -> String {
-        let mut exec_string = String::new();
+/// Booleans, numbers and already quoted strings are kept, anything else becomes a string.
+pub fn pref_value(value: &str) -> String {
+    let value = value.trim();
 
-        if self.isolate_profile {
-            let mut profile_dir = PathBuf::new();
+    if value == "true"
+        || value == "false"
+        || value.parse::<i64>().is_ok()
+        || (value.len() >= 2 && value.starts_with('"') && value.ends_with('"'))
+    {
+        value.to_string()
+    } else {
+        quote_string(value)
+    }
+}
 
-            let mut xdg_data_home = home_dir();
-            xdg_data_home.push(".local/share");
-            let ice_dir = xdg_data_home.join("ice");
-            profile_dir.push(ice_dir.join("profiles").join(&self.codename));
+/// Escapes a multi-line text so it fits into a single desktop entry value.
+pub fn escape_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+        .replace('\r', "\\r")
+}
 
-            let profile_path = profile_dir.to_str().unwrap();
+pub fn unescape_value(value: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
 
-            exec_string = format!(
-                "{} --portable --wmclass WebApp-{} --profile {} ",
-                self.exec, self.codename, profile_path
-            );
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
         }
 
-        if self.is_incognito {
-            exec_string.push_str("--private-browsing ");
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('s') => unescaped.push(' '),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
         }
+    }
 
-        if !self.custom_parameters.is_empty() {
-            exec_string.push_str(&format!("{} ", self.custom_parameters));
+    unescaped
+}
+
+/// Splits a desktop entry list value, `\;` stands for a literal semicolon.
+pub fn split_list(value: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut item = String::new();
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&';') => {
+                item.push(';');
+                chars.next();
+            }
+            ';' => items.push(std::mem::take(&mut item)),
+            _ => item.push(c),
         }
+    }
 
-        exec_string.push_str(&format!("--no-remote --current-tab {}", self.url));
+    items.push(item);
 
-        exec_string
-    }
+    items
+        .into_iter()
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
 
-    fn exec_string(&self) -> String {
-        match self.web_browser._type {
-            BrowserType::Firefox => self.exec_firefox("firefox"),
-            BrowserType::FirefoxFlatpak => self.exec_firefox("firefox"),
-            BrowserType::Librewolf => self.exec_firefox("librewolf"),
-            BrowserType::WaterfoxFlatpak => self.exec_firefox("waterfox"),
-            BrowserType::Chromium => self.exec_chromium(),
-            BrowserType::Falkon => self.exec_falkon(),
-            _ => String::new(),
+pub fn join_list(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|item| format!("{};", item.replace(';', "\\;")))
+        .collect()
+}
+
+/// Copies packed `.xpi` extensions into the `extensions` directory of a Firefox profile.
+/// Firefox only picks up files named after the add-on ID, e.g. `uBlock0@raymondhill.net.xpi`.
+/// Extensions removed from the app are uninstalled again, extensions the user
+/// installed from within Firefox are left alone.
+pub fn install_firefox_extensions(extensions: &[String], profile_path: &Path) -> Vec<PathBuf> {
+    let mut installed = Vec::new();
+    let mut ids = Vec::new();
+    let extensions_dir = profile_path.join("extensions");
+    let installed_list = extensions_dir.join(INSTALLED_EXTENSIONS_FILE);
+
+    for extension in extensions.iter().map(Path::new) {
+        if !extension.is_file() || extension.extension() != Some(OsStr::new("xpi")) {
+            tracing::warn!("skipping {:?}, not a packed Firefox extension", extension);
+            continue;
         }
-    }
 
-    pub fn create(&self) -> Result<()> {
-        let mut output = File::create(&self.path)?;
+        let Some(id) = firefox_extension_id(extension) else {
+            tracing::warn!("skipping {:?}, its manifest has no add-on ID", extension);
+            continue;
+        };
 
-        writeln!(output, "[Desktop Entry]")?;
-        writeln!(output, "Version=1.0")?;
-        writeln!(output, "Name={}", self.name)?;
-        writeln!(output, "Comment=Web App")?;
-        writeln!(output, "Exec={}", self.exec_string())?;
-        writeln!(output, "Terminal=false")?;
-        writeln!(output, "Type=Application")?;
-        writeln!(output, "Icon={}", self.icon)?;
-        writeln!(output, "Categories=GTK;{};", self.category)?;
-        writeln!(output, "MimeType=text/html;text/xml;application/xhtml_xml;")?;
-        writeln!(output, "StartupWMClass=WebApp-{}", self.codename)?;
-        writeln!(output, "StartupNotify=true")?;
-        writeln!(output, "X-MultipleArgs=false")?;
-        writeln!(output, "X-WebApp-Browser={}", self.web_browser.name)?;
-        writeln!(output, "X-WebApp-URL={}", self.url)?;
-        writeln!(output, "X-WebApp-Navbar={}", self.navbar)?;
-        writeln!(output, "X-WebApp-PrivateWindow={}", self.is_incognito)?;
-        writeln!(output, "X-WebApp-Isolated={}", self.isolate_profile)?;
-        writeln!(
-            output,
-            "X-WebApp-CustomParameters={}",
-            self.custom_parameters
-        )?;
+        let destination = extensions_dir.join(format!("{}.xpi", id));
 
-        Ok(())
+        if create_dir_all(&extensions_dir).is_ok() && copy(extension, &destination).is_ok() {
+            installed.push(destination);
+            ids.push(id);
+        } else {
+            tracing::error!("cant install extension {:?}", extension);
+        }
     }
 
-    pub fn delete(&self) -> Result<()> {
-        let exist = self.path.as_path().exists();
+    let previous = fs::read_to_string(&installed_list).unwrap_or_default();
 
-        match exist {
-            true => {
-                remove_file(&self.path)?;
-            }
-            false => {
-                tracing::error!("file not found");
+    for id in previous.lines().filter(|id| !id.is_empty()) {
+        if !ids.iter().any(|installed| installed == id) {
+            if let Err(e) = remove_file(extensions_dir.join(format!("{}.xpi", id))) {
+                tracing::warn!("cant uninstall extension {}: {}", id, e);
             }
         }
+    }
 
-        let mut profile_dir = home_dir();
+    if !ids.is_empty() || !previous.is_empty() {
+        let list: String = ids.iter().map(|id| format!("{}\n", id)).collect();
 
-        match self.web_browser._type {
-            BrowserType::FirefoxFlatpak => {
-                profile_dir.push(".var/app/org.mozilla.firefox/data/ice/firefox")
-            }
-            BrowserType::Librewolf => {
-                profile_dir.push(".var/app/io.gitlab.librewolf-community/data/ice/librewolf")
-            }
-            BrowserType::WaterfoxFlatpak => {
-                profile_dir.push(".var/app/net.waterfox.waterfox/data/ice/waterfox")
-            }
-            _ => {}
-        };
+        if let Err(e) = write_atomic(&installed_list, list.as_bytes()) {
+            tracing::error!("cant save installed extensions: {}", e);
+        }
+    }
 
-        let profile_path = profile_dir.join(&self.codename);
+    installed
+}
 
-        if remove_dir_all(profile_path).is_ok() {
-            tracing::info!("Removed firefox profile directory.");
-        };
+/// Extensions installed by [`install_firefox_extensions`], one add-on ID per line.
+const INSTALLED_EXTENSIONS_FILE: &str = "webapps-installed";
 
-        Ok(())
-    }
+/// Add-on ID of a packed extension, which Firefox expects as its file name.
+fn firefox_extension_id(xpi: &Path) -> Option<String> {
+    let mut archive = zip::ZipArchive::new(File::open(xpi).ok()?).ok()?;
+    let mut manifest = String::new();
+
+    archive
+        .by_name("manifest.json")
+        .ok()?
+        .read_to_string(&mut manifest)
+        .ok()?;
+
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).ok()?;
+
+    // `applications` is the name older extensions still use
+    ["browser_specific_settings", "applications"]
+        .iter()
+        .find_map(|key| manifest[key]["gecko"]["id"].as_str())
+        .map(str::to_string)
+}
+
+/// Names of the profile groups used by web apps of the given browser.
+pub fn profile_groups(browser: &Browser) -> Vec<String> {
+    let mut groups: Vec<String> = get_webapps()
+        .into_iter()
+        .flatten()
+        .filter(|app| app.web_browser.name == browser.name && !app.profile_group.is_empty())
+        .map(|app| app.profile_group)
+        .collect();
+
+    groups.sort();
+    groups.dedup();
+    groups
+}
+
+/// A web app of the given browser in the profile group, to read the shared settings from.
+pub fn profile_group_member(browser: &Browser, group: &str) -> Option<WebAppLauncher> {
+    get_webapps()
+        .into_iter()
+        .flatten()
+        .find(|app| app.web_browser.name == browser.name && app.profile_group == group)
 }
 
 pub fn get_webapps() -> Vec<Result<WebAppLauncher>> {
@@ -535,6 +2298,284 @@ pub enum BrowserType {
     WaterfoxFlatpak,
     Chromium,
     Falkon,
+    Qutebrowser,
+}
+
+impl BrowserType {
+    pub fn is_firefox(&self) -> bool {
+        matches!(
+            self,
+            BrowserType::Firefox
+                | BrowserType::FirefoxFlatpak
+                | BrowserType::Librewolf
+                | BrowserType::WaterfoxFlatpak
+        )
+    }
+
+    /// Whether an initial window size and position can be applied. Firefox has no
+    /// switch for the position and takes it from the web app's own profile instead.
+    pub fn supports_window_geometry(&self) -> bool {
+        self.is_firefox() || *self == BrowserType::Chromium
+    }
+
+    /// Whether profiles of both browsers share one on-disk format.
+    pub fn same_engine(&self, other: &BrowserType) -> bool {
+        (self.is_firefox() && other.is_firefox()) || self == other
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayMode {
+    Normal,
+    Fullscreen,
+    Kiosk,
+}
+
+impl DisplayMode {
+    pub const ALL: [DisplayMode; 3] = [
+        DisplayMode::Normal,
+        DisplayMode::Fullscreen,
+        DisplayMode::Kiosk,
+    ];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "fullscreen" => DisplayMode::Fullscreen,
+            "kiosk" => DisplayMode::Kiosk,
+            _ => DisplayMode::Normal,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            DisplayMode::Normal => "normal",
+            DisplayMode::Fullscreen => "fullscreen",
+            DisplayMode::Kiosk => "kiosk",
+        }
+    }
+}
+
+impl std::fmt::Display for DisplayMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DisplayMode::Normal => write!(f, "{}", fl!("display-mode", "normal")),
+            DisplayMode::Fullscreen => write!(f, "{}", fl!("display-mode", "fullscreen")),
+            DisplayMode::Kiosk => write!(f, "{}", fl!("display-mode", "kiosk")),
+        }
+    }
+}
+
+/// What happens to the old profile when editing changes where the profile lives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileMigration {
+    Move,
+    Copy,
+    Discard,
+}
+
+impl ProfileMigration {
+    pub const ALL: [ProfileMigration; 3] = [
+        ProfileMigration::Move,
+        ProfileMigration::Copy,
+        ProfileMigration::Discard,
+    ];
+}
+
+impl std::fmt::Display for ProfileMigration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileMigration::Move => write!(f, "{}", fl!("profile-migration", "move")),
+            ProfileMigration::Copy => write!(f, "{}", fl!("profile-migration", "copy")),
+            ProfileMigration::Discard => write!(f, "{}", fl!("profile-migration", "discard")),
+        }
+    }
+}
+
+/// Zoom levels in percent offered in the creator.
+pub const ZOOM_LEVELS: [u32; 12] = [50, 67, 75, 80, 90, 100, 110, 125, 150, 175, 200, 250];
+pub const DEFAULT_ZOOM: u32 = 100;
+
+/// Zoom in percent as a scale factor, e.g. `0.75` for 75%.
+fn zoom_factor(zoom: u32) -> String {
+    (zoom as f32 / 100.).to_string()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DisplayBackend {
+    Default,
+    Wayland,
+    X11,
+}
+
+impl DisplayBackend {
+    pub const ALL: [DisplayBackend; 3] = [
+        DisplayBackend::Default,
+        DisplayBackend::Wayland,
+        DisplayBackend::X11,
+    ];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "wayland" => DisplayBackend::Wayland,
+            "x11" => DisplayBackend::X11,
+            _ => DisplayBackend::Default,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            DisplayBackend::Default => "default",
+            DisplayBackend::Wayland => "wayland",
+            DisplayBackend::X11 => "x11",
+        }
+    }
+}
+
+impl std::fmt::Display for DisplayBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DisplayBackend::Default => write!(f, "{}", fl!("display-backend", "default")),
+            DisplayBackend::Wayland => write!(f, "{}", fl!("display-backend", "wayland")),
+            DisplayBackend::X11 => write!(f, "{}", fl!("display-backend", "x11")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionPolicy {
+    Keep,
+    ClearCookies,
+    Restore,
+}
+
+impl SessionPolicy {
+    pub const ALL: [SessionPolicy; 3] = [
+        SessionPolicy::Keep,
+        SessionPolicy::ClearCookies,
+        SessionPolicy::Restore,
+    ];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "clear-cookies" => SessionPolicy::ClearCookies,
+            "restore" => SessionPolicy::Restore,
+            _ => SessionPolicy::Keep,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            SessionPolicy::Keep => "keep",
+            SessionPolicy::ClearCookies => "clear-cookies",
+            SessionPolicy::Restore => "restore",
+        }
+    }
+}
+
+impl std::fmt::Display for SessionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionPolicy::Keep => write!(f, "{}", fl!("session-policy", "keep")),
+            SessionPolicy::ClearCookies => {
+                write!(f, "{}", fl!("session-policy", "clear-cookies"))
+            }
+            SessionPolicy::Restore => write!(f, "{}", fl!("session-policy", "restore")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    System,
+    Dark,
+    Light,
+}
+
+impl ColorScheme {
+    pub const ALL: [ColorScheme; 3] = [ColorScheme::System, ColorScheme::Dark, ColorScheme::Light];
+
+    pub fn from_key(key: &str) -> Self {
+        match key {
+            "dark" => ColorScheme::Dark,
+            "light" => ColorScheme::Light,
+            _ => ColorScheme::System,
+        }
+    }
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            ColorScheme::System => "system",
+            ColorScheme::Dark => "dark",
+            ColorScheme::Light => "light",
+        }
+    }
+}
+
+impl std::fmt::Display for ColorScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorScheme::System => write!(f, "{}", fl!("color-scheme", "system")),
+            ColorScheme::Dark => write!(f, "{}", fl!("color-scheme", "dark")),
+            ColorScheme::Light => write!(f, "{}", fl!("color-scheme", "light")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserAgent {
+    Default,
+    DesktopChrome,
+    DesktopFirefox,
+    MobileSafari,
+    Custom,
+}
+
+impl UserAgent {
+    pub const ALL: [UserAgent; 5] = [
+        UserAgent::Default,
+        UserAgent::DesktopChrome,
+        UserAgent::DesktopFirefox,
+        UserAgent::MobileSafari,
+        UserAgent::Custom,
+    ];
+
+    pub fn value(&self) -> Option<&'static str> {
+        match self {
+            UserAgent::DesktopChrome => Some(
+                "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36",
+            ),
+            UserAgent::DesktopFirefox => {
+                Some("Mozilla/5.0 (X11; Linux x86_64; rv:127.0) Gecko/20100101 Firefox/127.0")
+            }
+            UserAgent::MobileSafari => Some(
+                "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1",
+            ),
+            UserAgent::Default | UserAgent::Custom => None,
+        }
+    }
+
+    pub fn from_value(value: &str) -> Self {
+        if value.is_empty() {
+            return UserAgent::Default;
+        }
+
+        UserAgent::ALL
+            .into_iter()
+            .find(|preset| preset.value() == Some(value))
+            .unwrap_or(UserAgent::Custom)
+    }
+}
+
+impl std::fmt::Display for UserAgent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserAgent::Default => write!(f, "{}", fl!("user-agent", "default")),
+            UserAgent::DesktopChrome => write!(f, "{}", fl!("user-agent", "desktop-chrome")),
+            UserAgent::DesktopFirefox => write!(f, "{}", fl!("user-agent", "desktop-firefox")),
+            UserAgent::MobileSafari => write!(f, "{}", fl!("user-agent", "mobile-safari")),
+            UserAgent::Custom => write!(f, "{}", fl!("user-agent", "custom")),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -749,6 +2790,50 @@ pub fn convert_raster_to_svg_format(img_slice: Bytes, save_path: &str) -> Result
     Ok(())
 }
 
+/// Saves a copy of the icon with the first letter of `label` in a badge at the
+/// bottom right corner, so accounts of one site can be told apart.
+pub fn badge_icon(icon_path: &Path, label: &str, save_path: &Path) -> Result<()> {
+    let data = fs::read(icon_path)?;
+
+    let mime = if is_svg(icon_path.to_str().unwrap_or_default()) {
+        "image/svg+xml"
+    } else {
+        image::guess_format(&data)
+            .map(|format| format.to_mime_type())
+            .unwrap_or("image/png")
+    };
+
+    let initial: String = label
+        .chars()
+        .find(|c| c.is_alphanumeric())
+        .map(|c| c.to_uppercase().collect())
+        .unwrap_or_default();
+
+    // pick the badge color from the label, the same account always gets the same one
+    let colors = [
+        "#3584e4", "#2ec27e", "#e66100", "#9141ac", "#e01b24", "#986a44",
+    ];
+    let color = colors[label.bytes().map(usize::from).sum::<usize>() % colors.len()];
+
+    let document = format!(
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"128\" height=\"128\" viewBox=\"0 0 128 128\">",
+            "<image x=\"0\" y=\"0\" width=\"128\" height=\"128\" href=\"data:{};base64,{}\"/>",
+            "<circle cx=\"98\" cy=\"98\" r=\"28\" fill=\"{}\" stroke=\"#ffffff\" stroke-width=\"4\"/>",
+            "<text x=\"98\" y=\"109\" text-anchor=\"middle\" font-family=\"sans-serif\" ",
+            "font-size=\"32\" font-weight=\"bold\" fill=\"#ffffff\">{}</text>",
+            "</svg>"
+        ),
+        mime,
+        BASE64_STANDARD.encode(&data),
+        color,
+        initial
+    );
+
+    create_dir_all(my_icons_location())?;
+    write_atomic(save_path, document.as_bytes())
+}
+
 pub fn move_icon(path: String, output_name: String) -> String {
     create_dir_all(my_icons_location()).expect("cant create folder for your icons");
 
@@ -826,3 +2911,426 @@ pub async fn image_handle(path: String) -> Option<pages::iconpicker::Icon> {
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points HOME and the XDG directories at a scratch directory, so the tests never
+    /// read or write the desktop entries, profiles and settings of the real user.
+    fn test_home() -> PathBuf {
+        static HOME: std::sync::OnceLock<PathBuf> = std::sync::OnceLock::new();
+
+        HOME.get_or_init(|| {
+            let home = std::env::temp_dir().join(format!("webapps-home-{}", std::process::id()));
+            let _ = remove_dir_all(&home);
+            create_dir_all(home.join(".local/share/applications")).unwrap();
+
+            std::env::set_var("HOME", &home);
+            std::env::set_var("XDG_CONFIG_HOME", home.join(".config"));
+            std::env::set_var("XDG_DATA_HOME", home.join(".local/share"));
+            std::env::set_var("XDG_CACHE_HOME", home.join(".cache"));
+            std::env::remove_var("FLATPAK_ID");
+
+            home
+        })
+        .clone()
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = test_home().join(name);
+        let _ = remove_dir_all(&dir);
+        create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn test_launcher(browser: Browser) -> WebAppLauncher {
+        test_home();
+
+        WebAppLauncher::new(
+            "Test App".to_string(),
+            Some("TestApp".to_string()),
+            "https://example.com".to_string(),
+            String::new(),
+            "Network".to_string(),
+            browser,
+            String::new(),
+            true,
+            false,
+            false,
+            DisplayMode::Normal,
+            None,
+            None,
+            Vec::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            Vec::new(),
+            String::new(),
+            false,
+            String::new(),
+            ColorScheme::System,
+            DisplayBackend::Default,
+            Vec::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            DEFAULT_ZOOM,
+            String::new(),
+            String::new(),
+            SessionPolicy::Keep,
+        )
+    }
+
+    fn browser(_type: BrowserType, name: &str, exec: &str) -> Browser {
+        test_home();
+        Browser::new(_type, name, exec, "")
+    }
+
+    fn firefox() -> Browser {
+        browser(BrowserType::Firefox, "Firefox", "firefox")
+    }
+
+    fn user_pref(prefs: &[(String, String)], key: &str) -> Option<String> {
+        prefs
+            .iter()
+            .find(|(existing, _)| existing == key)
+            .map(|(_, value)| value.clone())
+    }
+
+    fn write_xpi(path: &Path, manifest: &str) {
+        let mut xpi = zip::ZipWriter::new(File::create(path).unwrap());
+        xpi.start_file("manifest.json", zip::write::FileOptions::default())
+            .unwrap();
+        xpi.write_all(manifest.as_bytes()).unwrap();
+        xpi.finish().unwrap();
+    }
+
+    #[test]
+    fn profile_groups_are_keyed_by_browser() {
+        let chromium = |name: &str, exec: &str| {
+            let mut launcher = test_launcher(browser(BrowserType::Chromium, name, exec));
+            launcher.profile_group = "work".to_string();
+            launcher.profile_path().unwrap()
+        };
+
+        let chrome = chromium("Google Chrome", "/usr/bin/google-chrome");
+        let brave = chromium("Brave", "/usr/bin/brave");
+
+        assert_ne!(chrome, brave);
+        assert_eq!(chrome, chromium("Google Chrome", "/usr/bin/google-chrome"));
+        assert!(chrome.ends_with("group-google-chrome-work"));
+    }
+
+    #[test]
+    fn duplicates_of_saved_launchers_keep_a_single_exec() {
+        let bin = test_home().join(".local/share/flatpak/exports/bin");
+        create_dir_all(&bin).unwrap();
+        File::create(bin.join("com.google.Chrome")).unwrap();
+
+        let saved = test_launcher(Browser::web_browser("Chrome".to_string()).unwrap());
+        saved.create().unwrap();
+
+        let launcher = WebAppLauncher::read(saved.path.clone(), saved.codename.clone()).unwrap();
+        assert_eq!(launcher.exec, launcher.web_browser.exec);
+
+        let duplicate = launcher
+            .duplicate("Test App Copy".to_string(), false)
+            .unwrap();
+        let exec = duplicate.exec_string();
+        assert_eq!(exec.matches(&duplicate.web_browser.exec).count(), 1);
+        assert_eq!(exec.matches("--app=").count(), 1);
+
+        remove_file(&saved.path).unwrap();
+    }
+
+    #[test]
+    fn accounts_of_saved_launchers_keep_a_single_exec() {
+        let bin = test_home().join(".local/share/flatpak/exports/bin");
+        create_dir_all(&bin).unwrap();
+        File::create(bin.join("com.google.Chrome")).unwrap();
+
+        let mut saved = test_launcher(Browser::web_browser("Chrome".to_string()).unwrap());
+        saved.codename = "TestAccounts".to_string();
+        saved.path = desktop_filepath("webapp-TestAccounts.desktop");
+        saved.create().unwrap();
+
+        let launcher = WebAppLauncher::read(saved.path.clone(), saved.codename.clone()).unwrap();
+        let account = launcher.add_account("Work", false).unwrap();
+        let exec = account.exec_string();
+        assert_eq!(exec.matches(&account.web_browser.exec).count(), 1);
+        assert_eq!(exec.matches("--app=").count(), 1);
+
+        remove_file(&saved.path).unwrap();
+    }
+
+    #[test]
+    fn syncing_a_group_keeps_the_exec_of_members() {
+        let bin = test_home().join(".local/share/flatpak/exports/bin");
+        create_dir_all(&bin).unwrap();
+        File::create(bin.join("com.google.Chrome")).unwrap();
+
+        let member = |codename: &str| {
+            let mut launcher = test_launcher(Browser::web_browser("Chrome".to_string()).unwrap());
+            launcher.codename = codename.to_string();
+            launcher.path = desktop_filepath(&format!("webapp-{}.desktop", codename));
+            launcher.profile_group = "sync".to_string();
+            launcher.create().unwrap();
+            launcher
+        };
+
+        let mut saved = member("TestSyncFirst");
+        let other = member("TestSyncSecond");
+
+        saved.download_dir = "/tmp/downloads".to_string();
+        saved.sync_profile_group().unwrap();
+
+        let other = WebAppLauncher::read(other.path.clone(), other.codename.clone()).unwrap();
+        assert_eq!(other.download_dir, saved.download_dir);
+
+        let exec = fs::read_to_string(&other.path).unwrap();
+        let exec = exec.lines().find(|line| line.starts_with("Exec=")).unwrap();
+        assert_eq!(exec.matches(&other.web_browser.exec).count(), 1);
+
+        remove_file(&saved.path).unwrap();
+        remove_file(&other.path).unwrap();
+    }
+
+    #[test]
+    fn closed_firefox_profiles_are_not_in_use() {
+        let mut launcher = test_launcher(firefox());
+        launcher.codename = "TestLock".to_string();
+        let profile = launcher.profile_path().unwrap();
+        let _ = remove_dir_all(&profile);
+        create_dir_all(&profile).unwrap();
+
+        File::create(profile.join(".parentlock")).unwrap();
+        assert!(!launcher.profile_in_use());
+
+        std::os::unix::fs::symlink("127.0.0.1:+1234", profile.join("lock")).unwrap();
+        assert!(launcher.profile_in_use());
+
+        remove_dir_all(profile).unwrap();
+    }
+
+    #[test]
+    fn failed_atomic_writes_remove_the_temp_file() {
+        let dir = test_dir("atomic");
+        let target = dir.join("launcher.desktop");
+
+        // a non-empty directory can't be replaced by the rename
+        create_dir_all(target.join("blocker")).unwrap();
+        assert!(write_atomic(&target, b"[Desktop Entry]").is_err());
+        assert!(!dir.join(".launcher.desktop.tmp").exists());
+
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn firefox_window_position_is_stored_in_xulstore() {
+        let dir = test_dir("xulstore");
+        let xulstore = dir.join("xulstore.json");
+        let mut launcher = test_launcher(firefox());
+
+        fs::write(
+            &xulstore,
+            r#"{"chrome://browser/content/browser.xhtml":{"main-window":{"sizemode":"normal"}}}"#,
+        )
+        .unwrap();
+
+        launcher.window_position = Some((100, -20));
+        assert!(launcher.create_firefox_xulstore(&dir));
+
+        let store: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&xulstore).unwrap()).unwrap();
+        let window = &store["chrome://browser/content/browser.xhtml"]["main-window"];
+        assert_eq!(window["screenX"], "100");
+        assert_eq!(window["screenY"], "-20");
+        assert_eq!(window["sizemode"], "normal");
+
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn group_members_share_profile_settings() {
+        let mut saved = test_launcher(firefox());
+        saved.download_dir = "/tmp/downloads".to_string();
+        saved.user_agent = "Custom".to_string();
+        saved.zoom = 150;
+
+        let mut member = test_launcher(firefox());
+        member.share_profile_settings(&saved);
+        assert_eq!(member.download_dir, saved.download_dir);
+        assert_eq!(member.user_agent, saved.user_agent);
+        assert_eq!(member.zoom, 150);
+
+        // chromium passes the user agent on the command line of each app
+        let chromium = browser(BrowserType::Chromium, "Chromium", "chromium");
+        let mut member = test_launcher(chromium);
+        member.share_profile_settings(&saved);
+        assert_eq!(member.download_dir, saved.download_dir);
+        assert!(member.user_agent.is_empty());
+    }
+
+    #[test]
+    fn firefox_extensions_are_installed_under_their_id() {
+        let dir = test_dir("xpi");
+        let downloads = dir.join("my downloads");
+        let profile = dir.join("web app profile");
+        create_dir_all(&downloads).unwrap();
+
+        let ublock = downloads.join("ublock origin-1.58.xpi");
+        let legacy = downloads.join("legacy addon.xpi");
+        let unpacked = downloads.join("unpacked extension");
+        write_xpi(
+            &ublock,
+            r#"{"browser_specific_settings": {"gecko": {"id": "uBlock0@raymondhill.net"}}}"#,
+        );
+        write_xpi(
+            &legacy,
+            r#"{"applications": {"gecko": {"id": "legacy@example.com"}}}"#,
+        );
+        create_dir_all(&unpacked).unwrap();
+
+        let extensions: Vec<String> = [&ublock, &legacy, &unpacked]
+            .iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect();
+        let installed = install_firefox_extensions(&extensions, &profile);
+
+        let extensions_dir = profile.join("extensions");
+        assert_eq!(
+            installed,
+            vec![
+                extensions_dir.join("uBlock0@raymondhill.net.xpi"),
+                extensions_dir.join("legacy@example.com.xpi"),
+            ]
+        );
+        assert!(installed.iter().all(|path| path.is_file()));
+
+        // extensions the user installed in firefox are kept
+        fs::write(extensions_dir.join("other@example.com.xpi"), "").unwrap();
+
+        install_firefox_extensions(&extensions[..1], &profile);
+        assert!(extensions_dir.join("uBlock0@raymondhill.net.xpi").is_file());
+        assert!(!extensions_dir.join("legacy@example.com.xpi").exists());
+        assert!(extensions_dir.join("other@example.com.xpi").is_file());
+
+        install_firefox_extensions(&[], &profile);
+        assert!(!extensions_dir.join("uBlock0@raymondhill.net.xpi").exists());
+        assert!(extensions_dir.join("other@example.com.xpi").is_file());
+
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unpacked_chromium_extensions_are_quoted() {
+        let dir = test_dir("unpacked");
+        let first = dir.join("dark reader");
+        let second = dir.join("privacy badger");
+        create_dir_all(&first).unwrap();
+        create_dir_all(&second).unwrap();
+
+        let mut launcher = test_launcher(browser(BrowserType::Chromium, "Chromium", "chromium"));
+        launcher.isolate_profile = false;
+        launcher.extensions = vec![
+            first.to_string_lossy().to_string(),
+            second.to_string_lossy().to_string(),
+            dir.join("packed.crx").to_string_lossy().to_string(),
+        ];
+
+        let exec = launcher.exec_chromium();
+        assert!(exec.contains(&format!(
+            "\"--load-extension={},{}\"",
+            first.display(),
+            second.display()
+        )));
+        assert!(!exec.contains("packed.crx"));
+
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn cleared_download_dir_is_removed_from_chromium_preferences() {
+        let dir = test_dir("preferences");
+        let preferences = dir.join("Default/Preferences");
+        let mut launcher = test_launcher(browser(BrowserType::Chromium, "Chromium", "chromium"));
+
+        launcher.download_dir = "/home/user/Downloads/Test App".to_string();
+        assert!(launcher.create_chromium_preferences(&dir));
+
+        let prefs: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&preferences).unwrap()).unwrap();
+        assert_eq!(
+            prefs["download"]["default_directory"],
+            "/home/user/Downloads/Test App"
+        );
+        assert_eq!(prefs["download"]["prompt_for_download"], false);
+
+        launcher.download_dir.clear();
+        assert!(launcher.create_chromium_preferences(&dir));
+
+        let prefs: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&preferences).unwrap()).unwrap();
+        assert!(prefs.pointer("/download/default_directory").is_none());
+        assert!(prefs.pointer("/download/prompt_for_download").is_none());
+
+        remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn cleared_options_reset_firefox_prefs() {
+        let dir = test_dir("userjs");
+        let user_js = dir.join("user.js");
+        let mut launcher = test_launcher(firefox());
+
+        launcher.user_agent = "Custom Agent".to_string();
+        launcher.proxy = "socks5://localhost:1080".to_string();
+        launcher.zoom = 150;
+        launcher.language = "de-DE".to_string();
+        launcher.session_policy = SessionPolicy::Restore;
+        launcher.color_scheme = ColorScheme::Dark;
+        assert!(launcher.create_firefox_userjs(user_js.clone()));
+
+        let prefs = parse_user_prefs(&fs::read_to_string(&user_js).unwrap());
+        assert_eq!(user_pref(&prefs, "network.proxy.type").unwrap(), "1");
+        assert_eq!(user_pref(&prefs, "browser.startup.page").unwrap(), "3");
+
+        // firefox copies the values into prefs.js while running
+        fs::write(
+            dir.join("prefs.js"),
+            "user_pref(\"general.useragent.override\", \"Custom Agent\");\nuser_pref(\"intl.accept_languages\", \"de-DE\");\nuser_pref(\"ui.systemUsesDarkTheme\", 1);\nuser_pref(\"browser.startup.homepage\", \"about:home\");\n",
+        )
+        .unwrap();
+
+        launcher.user_agent.clear();
+        launcher.proxy.clear();
+        launcher.zoom = DEFAULT_ZOOM;
+        launcher.language.clear();
+        launcher.session_policy = SessionPolicy::Keep;
+        launcher.color_scheme = ColorScheme::System;
+        assert!(launcher.create_firefox_userjs(user_js.clone()));
+
+        let prefs = parse_user_prefs(&fs::read_to_string(&user_js).unwrap());
+        assert!(user_pref(&prefs, "general.useragent.override").is_none());
+
+        for (key, value) in [
+            ("network.proxy.type", "5"),
+            ("network.proxy.socks", "\"\""),
+            ("layout.css.devPixelsPerPx", "\"-1.0\""),
+            ("intl.locale.requested", "\"\""),
+            ("browser.startup.page", "1"),
+            ("layout.css.prefers-color-scheme.content-override", "2"),
+        ] {
+            assert_eq!(user_pref(&prefs, key).as_deref(), Some(value), "{}", key);
+        }
+
+        let prefs_js = fs::read_to_string(dir.join("prefs.js")).unwrap();
+        assert!(!prefs_js.contains("general.useragent.override"));
+        assert!(!prefs_js.contains("intl.accept_languages"));
+        assert!(!prefs_js.contains("ui.systemUsesDarkTheme"));
+        assert!(prefs_js.contains("browser.startup.homepage"));
+
+        remove_dir_all(dir).unwrap();
+    }
+}
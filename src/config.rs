@@ -0,0 +1,42 @@
+use cosmic::cosmic_config::{self, cosmic_config_derive::CosmicConfigEntry, CosmicConfigEntry};
+use serde::{Deserialize, Serialize};
+
+use crate::common::DisplayBackend;
+
+pub const APP_ID: &str = "io.github.elevenhsoft.WebApps";
+pub const CONFIG_VERSION: u64 = 1;
+
+/// Global defaults shared by all web apps.
+#[derive(Debug, Clone, CosmicConfigEntry, Deserialize, Serialize, PartialEq, Eq)]
+#[version = 1]
+pub struct Config {
+    pub display_backend: DisplayBackend,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            display_backend: DisplayBackend::Default,
+        }
+    }
+}
+
+impl Config {
+    pub fn handler() -> Option<cosmic_config::Config> {
+        cosmic_config::Config::new(APP_ID, CONFIG_VERSION)
+            .map_err(|e| tracing::error!("cant open settings: {}", e))
+            .ok()
+    }
+
+    pub fn load() -> Self {
+        let Some(handler) = Self::handler() else {
+            return Config::default();
+        };
+
+        // keys that were never saved are reported as errors too
+        Config::get_entry(&handler).unwrap_or_else(|(errors, config)| {
+            tracing::debug!("settings loaded with errors: {:?}", errors);
+            config
+        })
+    }
+}
@@ -37,6 +37,15 @@ impl IconCache {
         bundle!("edit-delete-symbolic", 16);
         bundle!("document-new-symbolic", 16);
         bundle!("go-home-symbolic", 16);
+        bundle!("edit-clear-all-symbolic", 16);
+        bundle!("edit-clear-symbolic", 16);
+        bundle!("edit-copy-symbolic", 16);
+        bundle!("edit-undo-symbolic", 16);
+        bundle!("folder-open-symbolic", 16);
+        bundle!("list-add-symbolic", 16);
+        bundle!("preferences-system-symbolic", 16);
+        bundle!("user-trash-symbolic", 16);
+        bundle!("window-close-symbolic", 16);
 
         Self { cache }
     }
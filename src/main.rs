@@ -1,8 +1,12 @@
+mod browser_profiles;
 mod common;
+mod config;
 mod icon_cache;
 mod localize;
 mod pages;
 mod supported_browsers;
+mod theme;
+mod trash;
 mod warning;
 
 use std::{os::unix::fs::PermissionsExt, process::ExitStatus};
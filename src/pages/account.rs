@@ -0,0 +1,84 @@
+use cosmic::{
+    iced::{Alignment, Length},
+    theme,
+    widget::{self, text, toggler, warning, Container},
+    Element,
+};
+
+use crate::{common::WebAppLauncher, fl, pages};
+
+#[derive(Debug, Clone)]
+pub struct AddAccount {
+    pub launcher: WebAppLauncher,
+    pub account: String,
+    pub badge: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Account(String),
+    Badge(bool),
+}
+
+impl AddAccount {
+    pub fn new(launcher: WebAppLauncher) -> Self {
+        Self {
+            launcher,
+            account: String::new(),
+            badge: true,
+        }
+    }
+
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Account(account) => self.account = account,
+            Message::Badge(badge) => self.badge = badge,
+        }
+    }
+
+    pub fn view(&self, warnings: String) -> Element<pages::Message> {
+        let account = widget::text_input(fl!("add-account", "account"), &self.account)
+            .on_input(|s| pages::Message::AddAccount(Message::Account(s)))
+            .width(Length::Fill);
+
+        let badge = toggler(fl!("add-account", "badge"), self.badge, |b| {
+            pages::Message::AddAccount(Message::Badge(b))
+        })
+        .width(Length::Fill);
+
+        let mut create = widget::button(Container::new(text(fl!("create"))).center_x())
+            .width(Length::Fill)
+            .style(theme::Button::Suggested);
+
+        if !self.account.trim().is_empty() {
+            create = create.on_press(pages::Message::DoneAddAccount);
+        }
+
+        let buttons = widget::row()
+            .push(create)
+            .push(
+                widget::button(Container::new(text(fl!("close"))).center_x())
+                    .on_press(pages::Message::OpenHome)
+                    .width(Length::Fill),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let column = widget::column()
+            .spacing(10)
+            .push(warning(warnings))
+            .push(
+                text(fl!(
+                    "add-account",
+                    "header",
+                    name = self.launcher.name.clone()
+                ))
+                .size(20),
+            )
+            .push(account)
+            .push(badge)
+            .push(buttons);
+
+        Container::new(column).padding(30).max_width(1000).into()
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+use cosmic::{
+    iced::{Alignment, Length},
+    theme,
+    widget::{self, text, Container},
+    Element,
+};
+
+use crate::{common::WebAppLauncher, fl, pages};
+
+#[derive(Debug, Clone)]
+pub struct ClearSiteData {
+    pub launcher: WebAppLauncher,
+    pub paths: Vec<PathBuf>,
+    pub in_use: bool,
+    pub busy: bool,
+}
+
+impl ClearSiteData {
+    pub fn new(launcher: WebAppLauncher) -> Self {
+        Self {
+            paths: launcher.site_data_paths(),
+            in_use: launcher.profile_in_use(),
+            launcher,
+            busy: false,
+        }
+    }
+
+    pub fn view(&self) -> Element<pages::Message> {
+        let mut paths = widget::column().spacing(5);
+
+        for path in &self.paths {
+            paths = paths.push(text(path.display().to_string()).size(12));
+        }
+
+        let name = self.launcher.name.clone();
+
+        let mut column = widget::column()
+            .spacing(10)
+            .push(text(fl!("clear-site-data", "header", name = name.clone())).size(20))
+            .push(text(fl!("clear-site-data", "paths")))
+            .push(paths);
+
+        if self.in_use {
+            column = column.push(text(fl!("clear-site-data", "in-use", name = name)));
+        }
+
+        if self.busy {
+            column = column.push(text(fl!("clear-site-data", "busy")));
+        }
+
+        let mut clear = widget::button(Container::new(text(fl!("clear-site-data"))).center_x())
+            .width(Length::Fill)
+            .style(theme::Button::Destructive);
+
+        if !self.in_use && !self.busy {
+            clear = clear.on_press(pages::Message::DoneClearSiteData);
+        }
+
+        let buttons = widget::row()
+            .push(clear)
+            .push(
+                widget::button(Container::new(text(fl!("close"))).center_x())
+                    .on_press(pages::Message::OpenHome)
+                    .width(Length::Fill),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        Container::new(column.push(buttons))
+            .padding(30)
+            .max_width(1000)
+            .into()
+    }
+}
@@ -1,14 +1,21 @@
 use cosmic::widget::{text, warning};
 use cosmic::{
     app::{message::app, Message as CosmicMessage},
-    iced::{id, Length},
+    iced::{id, Alignment, Length},
+    iced_widget::Scrollable,
     style, theme,
     widget::{self, dropdown, toggler, Container},
     Command, Element,
 };
 
 use crate::{
-    common::{get_supported_browsers, icon_cache_get, url_valid, Browser, BrowserType},
+    browser_profiles::{browser_profiles, BrowserProfile},
+    common::{
+        env_var_name_valid, firefox_defaults_location, get_supported_browsers, icon_cache_get,
+        parse_pair, profile_group_member, profile_groups, split_list, url_valid, Browser,
+        BrowserType, ColorScheme, DisplayBackend, DisplayMode, ProfileMigration, SessionPolicy,
+        UserAgent, DEFAULT_ZOOM, ZOOM_LEVELS,
+    },
     fl,
     pages::{self, iconpicker::IconType},
     warning::{WarnAction, WarnMessages},
@@ -31,11 +38,48 @@ pub struct AppCreator {
     pub app_navbar: bool,
     pub app_incognito: bool,
     pub app_isolated: bool,
+    pub app_display_modes: Vec<String>,
+    pub app_display_mode: DisplayMode,
+    pub app_window_width: String,
+    pub app_window_height: String,
+    pub app_window_x: String,
+    pub app_window_y: String,
+    pub app_extensions: String,
+    pub app_user_agents: Vec<String>,
+    pub app_user_agent: String,
+    pub app_proxy: String,
+    pub app_proxy_bypass: String,
+    pub app_firefox_prefs: Vec<(String, String)>,
+    pub app_user_chrome_css: String,
+    pub app_theme_colors: bool,
+    pub app_user_content_css: String,
+    pub app_color_schemes: Vec<String>,
+    pub app_color_scheme: ColorScheme,
+    pub app_display_backends: Vec<String>,
+    pub app_display_backend: DisplayBackend,
+    pub app_zooms: Vec<String>,
+    pub app_zoom: u32,
+    pub app_language: String,
+    pub app_download_dir: String,
+    pub app_session_policies: Vec<String>,
+    pub app_session_policy: SessionPolicy,
+    pub app_env_vars: Vec<(String, String)>,
+    pub app_profile_groups: Vec<String>,
+    pub app_profile_options: Vec<String>,
+    pub app_profile_group: String,
+    pub app_new_profile_group: bool,
+    pub app_browser_profiles: Vec<BrowserProfile>,
+    pub app_browser_profile_options: Vec<String>,
+    pub app_browser_profile: String,
+    pub app_account_of: String,
+    pub app_profile_migrations: Vec<String>,
+    pub app_profile_migration: ProfileMigration,
     pub selected_icon: Option<pages::iconpicker::Icon>,
     pub app_browsers: Vec<Browser>,
     pub selected_browser: Option<usize>,
     pub dialog_open: bool,
     pub edit_mode: bool,
+    pub busy: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -45,15 +89,48 @@ pub enum Message {
     Arguments(String),
     Browser(usize),
     Category(usize),
+    DisplayMode(usize),
+    ColorScheme(usize),
+    DisplayBackend(usize),
+    Zoom(usize),
+    Language(String),
+    DownloadDir(String),
+    SessionPolicy(usize),
+    EnvVar(KeyValue),
+    ProfileGroup(usize),
+    ProfileGroupName(String),
+    ProfileMigration(usize),
+    BrowserProfile(usize),
+    WindowWidth(String),
+    WindowHeight(String),
+    WindowX(String),
+    WindowY(String),
+    Extensions(String),
+    UserAgentPreset(usize),
+    UserAgent(String),
+    Proxy(String),
+    ProxyBypass(String),
+    FirefoxPref(KeyValue),
+    UserChromeCss(String),
+    UserContentCss(String),
 
     Clicked(Buttons),
 }
 
+#[derive(Debug, Clone)]
+pub enum KeyValue {
+    Add,
+    Remove(usize),
+    Key(usize, String),
+    Value(usize, String),
+}
+
 #[derive(Debug, Clone)]
 pub enum Buttons {
     Navbar(bool),
     IsolatedProfile(bool),
     Incognito(bool),
+    ThemeColors(bool),
 }
 
 impl AppCreator {
@@ -89,11 +166,51 @@ impl AppCreator {
             app_navbar: false,
             app_incognito: false,
             app_isolated: true,
+            app_display_modes: DisplayMode::ALL.iter().map(|m| m.to_string()).collect(),
+            app_display_mode: DisplayMode::Normal,
+            app_window_width: String::new(),
+            app_window_height: String::new(),
+            app_window_x: String::new(),
+            app_window_y: String::new(),
+            app_extensions: String::new(),
+            app_user_agents: UserAgent::ALL.iter().map(|ua| ua.to_string()).collect(),
+            app_user_agent: String::new(),
+            app_proxy: String::new(),
+            app_proxy_bypass: String::new(),
+            app_firefox_prefs: Vec::new(),
+            app_user_chrome_css: String::new(),
+            app_theme_colors: false,
+            app_user_content_css: String::new(),
+            app_color_schemes: ColorScheme::ALL.iter().map(|c| c.to_string()).collect(),
+            app_color_scheme: ColorScheme::System,
+            app_display_backends: DisplayBackend::ALL.iter().map(|b| b.to_string()).collect(),
+            app_display_backend: DisplayBackend::Default,
+            app_zooms: ZOOM_LEVELS.iter().map(|z| format!("{}%", z)).collect(),
+            app_zoom: DEFAULT_ZOOM,
+            app_language: String::new(),
+            app_download_dir: String::new(),
+            app_session_policies: SessionPolicy::ALL.iter().map(|p| p.to_string()).collect(),
+            app_session_policy: SessionPolicy::Keep,
+            app_env_vars: Vec::new(),
+            app_profile_groups: Vec::new(),
+            app_profile_options: Vec::new(),
+            app_profile_group: String::new(),
+            app_new_profile_group: false,
+            app_browser_profiles: Vec::new(),
+            app_browser_profile_options: Vec::new(),
+            app_browser_profile: String::new(),
+            app_account_of: String::new(),
+            app_profile_migrations: ProfileMigration::ALL
+                .iter()
+                .map(|m| m.to_string())
+                .collect(),
+            app_profile_migration: ProfileMigration::Move,
             selected_icon: None,
             app_browsers: browsers,
             selected_browser: Some(0),
             dialog_open: false,
             edit_mode: false,
+            busy: false,
         }
     }
 
@@ -145,8 +262,9 @@ impl AppCreator {
                 let browser = &self.app_browsers[idx];
                 self.selected_browser = Some(idx);
                 self.app_browser = browser.clone();
+                self.refresh_profiles();
 
-                match browser._type {
+                let browser_warning = match self.app_browser._type {
                     BrowserType::NoBrowser => Command::perform(async {}, |_| {
                         app(pages::Message::Warning((
                             WarnAction::Add,
@@ -159,13 +277,158 @@ impl AppCreator {
                             WarnMessages::AppBrowser,
                         )))
                     }),
-                }
+                };
+
+                Command::batch([browser_warning, self.geometry_warnings()])
             }
             Message::Category(idx) => {
                 self.app_category.clone_from(&self.app_categories[idx]);
                 self.selected_category = idx;
                 Command::none()
             }
+            Message::DisplayMode(idx) => {
+                self.app_display_mode = DisplayMode::ALL[idx];
+                Command::none()
+            }
+            Message::ColorScheme(idx) => {
+                self.app_color_scheme = ColorScheme::ALL[idx];
+                Command::none()
+            }
+            Message::DisplayBackend(idx) => {
+                self.app_display_backend = DisplayBackend::ALL[idx];
+                Command::none()
+            }
+            Message::Zoom(idx) => {
+                self.app_zoom = ZOOM_LEVELS[idx];
+                Command::none()
+            }
+            Message::Language(language) => {
+                self.app_language = language;
+                Command::none()
+            }
+            Message::DownloadDir(path) => {
+                self.app_download_dir = path;
+                Command::none()
+            }
+            Message::SessionPolicy(idx) => {
+                self.app_session_policy = SessionPolicy::ALL[idx];
+                Command::none()
+            }
+            Message::EnvVar(change) => {
+                update_key_values(&mut self.app_env_vars, change);
+
+                if self.env_vars_valid() {
+                    Command::perform(async {}, |_| {
+                        app(pages::Message::Warning((
+                            WarnAction::Remove,
+                            WarnMessages::EnvVarName,
+                        )))
+                    })
+                } else {
+                    Command::perform(async {}, |_| {
+                        app(pages::Message::Warning((
+                            WarnAction::Add,
+                            WarnMessages::EnvVarName,
+                        )))
+                    })
+                }
+            }
+            Message::ProfileGroup(idx) => {
+                match idx {
+                    0 => {
+                        self.app_profile_group.clear();
+                        self.app_new_profile_group = false;
+                    }
+                    1 => {
+                        self.app_profile_group.clear();
+                        self.app_new_profile_group = true;
+                    }
+                    _ => {
+                        self.app_profile_group
+                            .clone_from(&self.app_profile_groups[idx - 2]);
+                        self.app_new_profile_group = false;
+                        self.load_group_settings();
+                    }
+                }
+                Command::none()
+            }
+            Message::BrowserProfile(idx) => {
+                match idx {
+                    0 => self.app_browser_profile.clear(),
+                    _ => self
+                        .app_browser_profile
+                        .clone_from(&self.app_browser_profiles[idx - 1].id),
+                }
+                self.geometry_warnings()
+            }
+            Message::ProfileMigration(idx) => {
+                self.app_profile_migration = ProfileMigration::ALL[idx];
+                Command::none()
+            }
+            Message::ProfileGroupName(name) => {
+                // the group name ends up in the profile directory name
+                self.app_profile_group = name
+                    .chars()
+                    .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_')
+                    .collect();
+                Command::none()
+            }
+            Message::WindowWidth(width) => {
+                self.app_window_width = width;
+                Self::geometry_warning(self.window_size_valid(), WarnMessages::WindowSize)
+            }
+            Message::WindowHeight(height) => {
+                self.app_window_height = height;
+                Self::geometry_warning(self.window_size_valid(), WarnMessages::WindowSize)
+            }
+            Message::WindowX(x) => {
+                self.app_window_x = x;
+                Self::geometry_warning(self.window_position_valid(), WarnMessages::WindowPosition)
+            }
+            Message::WindowY(y) => {
+                self.app_window_y = y;
+                Self::geometry_warning(self.window_position_valid(), WarnMessages::WindowPosition)
+            }
+            Message::Extensions(extensions) => {
+                self.app_extensions = extensions;
+                Command::none()
+            }
+            Message::UserAgentPreset(idx) => {
+                match UserAgent::ALL[idx] {
+                    UserAgent::Default => self.app_user_agent.clear(),
+                    UserAgent::Custom => {}
+                    preset => {
+                        if let Some(value) = preset.value() {
+                            self.app_user_agent = value.to_string();
+                        }
+                    }
+                }
+                Command::none()
+            }
+            Message::UserAgent(user_agent) => {
+                self.app_user_agent = user_agent;
+                Command::none()
+            }
+            Message::Proxy(proxy) => {
+                self.app_proxy = proxy;
+                Command::none()
+            }
+            Message::ProxyBypass(bypass) => {
+                self.app_proxy_bypass = bypass;
+                Command::none()
+            }
+            Message::FirefoxPref(message) => {
+                update_key_values(&mut self.app_firefox_prefs, message);
+                Command::none()
+            }
+            Message::UserChromeCss(path) => {
+                self.app_user_chrome_css = path;
+                Command::none()
+            }
+            Message::UserContentCss(css) => {
+                self.app_user_content_css = css;
+                Command::none()
+            }
 
             Message::Clicked(buttons) => match buttons {
                 Buttons::Navbar(selected) => {
@@ -181,12 +444,230 @@ impl AppCreator {
                 Buttons::Incognito(selected) => {
                     self.app_incognito = selected;
 
+                    Command::none()
+                }
+                Buttons::ThemeColors(selected) => {
+                    self.app_theme_colors = selected;
+
                     Command::none()
                 }
             },
         }
     }
 
+    fn window_size_shown(&self) -> bool {
+        self.app_browser._type.supports_window_geometry()
+    }
+
+    // Firefox reads the position from the web app's own profile
+    fn window_position_shown(&self) -> bool {
+        self.window_size_shown()
+            && (!self.app_browser._type.is_firefox() || self.uses_own_profile())
+    }
+
+    pub fn window_size(&self) -> Option<(u32, u32)> {
+        if !self.window_size_shown() {
+            return None;
+        }
+
+        parse_pair(
+            &format!("{}x{}", self.app_window_width, self.app_window_height),
+            'x',
+        )
+    }
+
+    pub fn window_position(&self) -> Option<(i32, i32)> {
+        if !self.window_position_shown() {
+            return None;
+        }
+
+        parse_pair(&format!("{},{}", self.app_window_x, self.app_window_y), ',')
+    }
+
+    // empty fields leave the geometry to the browser, hidden ones are not saved
+    fn window_size_valid(&self) -> bool {
+        let (width, height) = (self.app_window_width.trim(), self.app_window_height.trim());
+
+        !self.window_size_shown()
+            || (width.is_empty() && height.is_empty())
+            || matches!(
+                (width.parse::<u32>(), height.parse::<u32>()),
+                (Ok(width), Ok(height)) if width > 0 && height > 0
+            )
+    }
+
+    fn window_position_valid(&self) -> bool {
+        let (x, y) = (self.app_window_x.trim(), self.app_window_y.trim());
+
+        !self.window_position_shown()
+            || (x.is_empty() && y.is_empty())
+            || (x.parse::<i32>().is_ok() && y.parse::<i32>().is_ok())
+    }
+
+    fn geometry_warnings(&self) -> Command<CosmicMessage<pages::Message>> {
+        Command::batch([
+            Self::geometry_warning(self.window_size_valid(), WarnMessages::WindowSize),
+            Self::geometry_warning(self.window_position_valid(), WarnMessages::WindowPosition),
+        ])
+    }
+
+    fn geometry_warning(
+        valid: bool,
+        message: WarnMessages,
+    ) -> Command<CosmicMessage<pages::Message>> {
+        let action = if valid {
+            WarnAction::Remove
+        } else {
+            WarnAction::Add
+        };
+
+        Command::perform(async {}, move |_| {
+            app(pages::Message::Warning((action, message)))
+        })
+    }
+
+    /// Options that would be dropped silently instead of making the launcher invalid.
+    pub fn options_valid(&self) -> bool {
+        self.window_size_valid() && self.window_position_valid() && self.env_vars_valid()
+    }
+
+    pub fn set_window_geometry(&mut self, size: Option<(u32, u32)>, position: Option<(i32, i32)>) {
+        let (width, height) = size
+            .map(|(w, h)| (w.to_string(), h.to_string()))
+            .unwrap_or_default();
+        let (x, y) = position
+            .map(|(x, y)| (x.to_string(), y.to_string()))
+            .unwrap_or_default();
+
+        self.app_window_width = width;
+        self.app_window_height = height;
+        self.app_window_x = x;
+        self.app_window_y = y;
+    }
+
+    pub fn extensions(&self) -> Vec<String> {
+        split_list(&self.app_extensions)
+    }
+
+    pub fn firefox_prefs(&self) -> Vec<(String, String)> {
+        self.app_firefox_prefs
+            .iter()
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .filter(|(key, _)| !key.is_empty())
+            .collect()
+    }
+
+    /// Reloads the profile groups and the browser's own profiles for the selected browser.
+    pub fn refresh_profiles(&mut self) {
+        self.app_browser_profiles = browser_profiles(&self.app_browser);
+
+        self.app_browser_profile_options = vec![fl!("browser-profile", "none")];
+        self.app_browser_profile_options.extend(
+            self.app_browser_profiles
+                .iter()
+                .map(|profile| profile.name.clone()),
+        );
+
+        if !self
+            .app_browser_profiles
+            .iter()
+            .any(|profile| profile.id == self.app_browser_profile)
+        {
+            self.app_browser_profile.clear();
+        }
+
+        self.app_profile_groups = profile_groups(&self.app_browser);
+
+        self.app_profile_options = vec![fl!("profile", "isolated"), fl!("profile", "new-group")];
+        self.app_profile_options.extend(
+            self.app_profile_groups
+                .iter()
+                .map(|group| fl!("profile", "join-group", group = group.clone())),
+        );
+
+        if !self.app_profile_group.is_empty()
+            && !self.app_profile_groups.contains(&self.app_profile_group)
+        {
+            self.app_new_profile_group = true;
+        }
+    }
+
+    /// Shows the settings of the joined group, they live in the shared profile.
+    fn load_group_settings(&mut self) {
+        let Some(member) = profile_group_member(&self.app_browser, &self.app_profile_group) else {
+            return;
+        };
+
+        match self.app_browser._type {
+            _ if self.app_browser._type.is_firefox() => {
+                self.app_navbar = member.navbar;
+                self.app_user_agent = member.user_agent;
+                self.app_proxy = member.proxy;
+                self.app_proxy_bypass = member.proxy_bypass;
+                self.app_firefox_prefs = member.firefox_prefs;
+                self.app_user_chrome_css = member.user_chrome_css;
+                self.app_theme_colors = member.theme_colors;
+                self.app_user_content_css = member.user_content_css;
+                self.app_color_scheme = member.color_scheme;
+                self.app_extensions = member.extensions.join("; ");
+                self.app_zoom = member.zoom;
+                self.app_language = member.language;
+            }
+            BrowserType::Qutebrowser => {
+                self.app_navbar = member.navbar;
+                self.app_user_agent = member.user_agent;
+                self.app_proxy = member.proxy;
+                self.app_color_scheme = member.color_scheme;
+                self.app_zoom = member.zoom;
+                self.app_language = member.language;
+            }
+            BrowserType::Chromium => {}
+            _ => return,
+        }
+
+        self.app_download_dir = member.download_dir;
+        self.app_session_policy = member.session_policy;
+    }
+
+    fn uses_own_profile(&self) -> bool {
+        if !self.app_browser_profile.is_empty() {
+            return false;
+        }
+
+        match self.app_browser._type {
+            BrowserType::Chromium | BrowserType::Falkon => self.app_isolated,
+            BrowserType::NoBrowser => false,
+            _ => true,
+        }
+    }
+
+    fn selected_profile_option(&self) -> usize {
+        if self.app_new_profile_group {
+            1
+        } else if self.app_profile_group.is_empty() {
+            0
+        } else {
+            self.app_profile_groups
+                .iter()
+                .position(|group| *group == self.app_profile_group)
+                .map_or(1, |idx| idx + 2)
+        }
+    }
+
+    pub fn env_vars(&self) -> Vec<(String, String)> {
+        self.app_env_vars
+            .iter()
+            .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+            .filter(|(key, _)| !key.is_empty())
+            .collect()
+    }
+
+    fn env_vars_valid(&self) -> bool {
+        self.env_vars()
+            .iter()
+            .all(|(key, _)| env_var_name_valid(key))
+    }
+
     fn icon_picker_icon(&self, icon: Option<pages::iconpicker::Icon>) -> Element<pages::Message> {
         let ico = if let Some(ico) = icon {
             match ico.icon {
@@ -274,6 +755,7 @@ impl AppCreator {
             BrowserType::FirefoxFlatpak => navbar_toggle,
             BrowserType::Librewolf => navbar_toggle,
             BrowserType::WaterfoxFlatpak => navbar_toggle,
+            BrowserType::Qutebrowser => navbar_toggle,
 
             _ => toggler(fl!("isolated-profile"), self.app_isolated, |b| {
                 pages::Message::Creator(Message::Clicked(Buttons::IsolatedProfile(b)))
@@ -287,10 +769,16 @@ impl AppCreator {
         .width(Length::Fill);
 
         let save_btn = if self.edit_mode {
-            widget::button(Container::new(text(fl!("edit"))).center_x())
-                .on_press(pages::Message::DoneEdit)
+            let button = widget::button(Container::new(text(fl!("edit"))).center_x())
                 .width(Length::Fill)
-                .style(theme::Button::Suggested)
+                .style(theme::Button::Suggested);
+
+            // the profile is still being moved or copied
+            if self.busy {
+                button
+            } else {
+                button.on_press(pages::Message::DoneEdit)
+            }
         } else {
             widget::button(Container::new(text(fl!("create"))).center_x())
                 .on_press(pages::Message::DoneCreate)
@@ -319,15 +807,384 @@ impl AppCreator {
             .push(creator_close)
             .spacing(10);
 
-        let view_column = widget::column()
+        let display_modes = dropdown(
+            &self.app_display_modes,
+            DisplayMode::ALL
+                .iter()
+                .position(|m| *m == self.app_display_mode),
+            |idx| pages::Message::Creator(Message::DisplayMode(idx)),
+        )
+        .width(Length::Fixed(200.));
+
+        let display_mode_row = widget::row()
+            .push(text(fl!("display-mode")).width(Length::Fill))
+            .push(display_modes)
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let color_schemes = dropdown(
+            &self.app_color_schemes,
+            ColorScheme::ALL
+                .iter()
+                .position(|c| *c == self.app_color_scheme),
+            |idx| pages::Message::Creator(Message::ColorScheme(idx)),
+        )
+        .width(Length::Fixed(200.));
+
+        let color_scheme_row = widget::row()
+            .push(text(fl!("color-scheme")).width(Length::Fill))
+            .push(color_schemes)
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let display_backends = dropdown(
+            &self.app_display_backends,
+            DisplayBackend::ALL
+                .iter()
+                .position(|b| *b == self.app_display_backend),
+            |idx| pages::Message::Creator(Message::DisplayBackend(idx)),
+        )
+        .width(Length::Fixed(200.));
+
+        let display_backend_row = widget::row()
+            .push(text(fl!("display-backend")).width(Length::Fill))
+            .push(display_backends)
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let zooms = dropdown(
+            &self.app_zooms,
+            ZOOM_LEVELS.iter().position(|z| *z == self.app_zoom),
+            |idx| pages::Message::Creator(Message::Zoom(idx)),
+        )
+        .width(Length::Fixed(200.));
+
+        let zoom_row = widget::row()
+            .push(text(fl!("zoom")).width(Length::Fill))
+            .push(zooms)
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let language_row = widget::row()
+            .push(text(fl!("language")).width(Length::Fill))
+            .push(
+                widget::text_input(fl!("language", "placeholder"), &self.app_language)
+                    .on_input(|s| pages::Message::Creator(Message::Language(s)))
+                    .width(Length::Fixed(200.)),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let session_policies = dropdown(
+            &self.app_session_policies,
+            SessionPolicy::ALL
+                .iter()
+                .position(|p| *p == self.app_session_policy),
+            |idx| pages::Message::Creator(Message::SessionPolicy(idx)),
+        )
+        .width(Length::Fixed(200.));
+
+        let session_policy_row = widget::row()
+            .push(text(fl!("session-policy")).width(Length::Fill))
+            .push(session_policies)
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let download_dir_row = widget::row()
+            .push(
+                widget::text_input(fl!("download-dir"), &self.app_download_dir)
+                    .on_input(|s| pages::Message::Creator(Message::DownloadDir(s)))
+                    .width(Length::Fill),
+            )
+            .push(
+                widget::button(icon_cache_get("folder-open-symbolic", 16))
+                    .on_press(pages::Message::OpenDownloadDirDialog)
+                    .padding(8)
+                    .style(style::Button::Icon),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let mut profile_row = widget::row()
+            .push(text(fl!("profile")).width(Length::Fill))
+            .push(
+                dropdown(
+                    &self.app_profile_options,
+                    Some(self.selected_profile_option()),
+                    |idx| pages::Message::Creator(Message::ProfileGroup(idx)),
+                )
+                .width(Length::Fixed(200.)),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        if self.app_new_profile_group {
+            profile_row = profile_row.push(
+                widget::text_input(fl!("profile", "group-name"), &self.app_profile_group)
+                    .on_input(|s| pages::Message::Creator(Message::ProfileGroupName(s)))
+                    .width(Length::Fixed(200.)),
+            );
+        }
+
+        let window_size_row = widget::row()
+            .push(text(fl!("window-size")).width(Length::Fill))
+            .push(
+                widget::text_input(fl!("window-size", "width"), &self.app_window_width)
+                    .on_input(|s| pages::Message::Creator(Message::WindowWidth(s)))
+                    .width(Length::Fixed(95.)),
+            )
+            .push(
+                widget::text_input(fl!("window-size", "height"), &self.app_window_height)
+                    .on_input(|s| pages::Message::Creator(Message::WindowHeight(s)))
+                    .width(Length::Fixed(95.)),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let window_position_row = widget::row()
+            .push(text(fl!("window-position")).width(Length::Fill))
+            .push(
+                widget::text_input(fl!("window-position", "x"), &self.app_window_x)
+                    .on_input(|s| pages::Message::Creator(Message::WindowX(s)))
+                    .width(Length::Fixed(95.)),
+            )
+            .push(
+                widget::text_input(fl!("window-position", "y"), &self.app_window_y)
+                    .on_input(|s| pages::Message::Creator(Message::WindowY(s)))
+                    .width(Length::Fixed(95.)),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let app_extensions = widget::text_input(fl!("extensions"), &self.app_extensions)
+            .on_input(|s| pages::Message::Creator(Message::Extensions(s)))
+            .width(Length::Fill);
+
+        let user_agent_presets = dropdown(
+            &self.app_user_agents,
+            UserAgent::ALL
+                .iter()
+                .position(|ua| *ua == UserAgent::from_value(&self.app_user_agent)),
+            |idx| pages::Message::Creator(Message::UserAgentPreset(idx)),
+        )
+        .width(Length::Fixed(200.));
+
+        let user_agent_row = widget::row()
+            .push(
+                widget::text_input(fl!("user-agent"), &self.app_user_agent)
+                    .on_input(|s| pages::Message::Creator(Message::UserAgent(s)))
+                    .width(Length::Fill),
+            )
+            .push(user_agent_presets)
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let proxy_row = widget::row()
+            .push(text(fl!("proxy")).width(Length::Fixed(120.)))
+            .push(
+                widget::text_input(fl!("proxy", "server"), &self.app_proxy)
+                    .on_input(|s| pages::Message::Creator(Message::Proxy(s)))
+                    .width(Length::Fill),
+            )
+            .push(
+                widget::text_input(fl!("proxy", "bypass"), &self.app_proxy_bypass)
+                    .on_input(|s| pages::Message::Creator(Message::ProxyBypass(s)))
+                    .width(Length::Fill),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let mut view_column = widget::column()
             .push(warning(warnings))
             .push(row)
             .push(app_arguments)
             .push(first_row)
             .push(end_row)
+            .push(display_mode_row)
+            .push(color_scheme_row)
+            .push(display_backend_row)
+            .push(zoom_row)
+            .push(language_row)
+            .push(session_policy_row)
+            .push(download_dir_row)
             .spacing(10)
             .padding(30);
 
-        Container::new(view_column).max_width(1000).into()
+        if !self.app_browser_profiles.is_empty() {
+            let browser_profiles = dropdown(
+                &self.app_browser_profile_options,
+                Some(
+                    self.app_browser_profiles
+                        .iter()
+                        .position(|profile| profile.id == self.app_browser_profile)
+                        .map_or(0, |idx| idx + 1),
+                ),
+                |idx| pages::Message::Creator(Message::BrowserProfile(idx)),
+            )
+            .width(Length::Fixed(200.));
+
+            let browser_profile_row = widget::row()
+                .push(text(fl!("browser-profile")).width(Length::Fill))
+                .push(browser_profiles)
+                .align_items(Alignment::Center)
+                .spacing(10);
+
+            view_column = view_column.push(browser_profile_row);
+        }
+
+        if self.uses_own_profile() {
+            view_column = view_column.push(profile_row);
+
+            if self.app_new_profile_group || !self.app_profile_group.is_empty() {
+                view_column = view_column.push(text(fl!("profile", "group-shared")));
+            }
+        }
+
+        if self.edit_mode {
+            let profile_migrations = dropdown(
+                &self.app_profile_migrations,
+                ProfileMigration::ALL
+                    .iter()
+                    .position(|m| *m == self.app_profile_migration),
+                |idx| pages::Message::Creator(Message::ProfileMigration(idx)),
+            )
+            .width(Length::Fixed(200.));
+
+            let profile_migration_row = widget::row()
+                .push(text(fl!("profile-migration")).width(Length::Fill))
+                .push(profile_migrations)
+                .align_items(Alignment::Center)
+                .spacing(10);
+
+            view_column = view_column.push(profile_migration_row);
+
+            if self.busy {
+                view_column = view_column.push(text(fl!("profile-migration", "busy")));
+            }
+        }
+
+        if self.window_size_shown() {
+            view_column = view_column.push(window_size_row);
+        }
+
+        if self.window_position_shown() {
+            view_column = view_column.push(window_position_row);
+        }
+
+        view_column = view_column
+            .push(app_extensions)
+            .push(user_agent_row)
+            .push(proxy_row)
+            .push(text(fl!("env-vars")))
+            .push(key_values_view(
+                &self.app_env_vars,
+                fl!("env-vars", "key"),
+                fl!("env-vars", "value"),
+                Message::EnvVar,
+            ));
+
+        if self.app_browser._type.is_firefox() {
+            let user_chrome_row = widget::row()
+                .push(
+                    widget::text_input(fl!("user-chrome-css"), &self.app_user_chrome_css)
+                        .on_input(|s| pages::Message::Creator(Message::UserChromeCss(s)))
+                        .width(Length::Fill),
+                )
+                .push(
+                    toggler(fl!("theme-colors"), self.app_theme_colors, |b| {
+                        pages::Message::Creator(Message::Clicked(Buttons::ThemeColors(b)))
+                    })
+                    .width(Length::Fixed(240.)),
+                )
+                .align_items(Alignment::Center)
+                .spacing(10);
+
+            let user_content_css =
+                widget::text_input(fl!("user-content-css"), &self.app_user_content_css)
+                    .on_input(|s| pages::Message::Creator(Message::UserContentCss(s)))
+                    .width(Length::Fill);
+
+            view_column = view_column
+                .push(user_chrome_row)
+                .push(user_content_css)
+                .push(text(fl!("firefox-prefs")))
+                .push(key_values_view(
+                    &self.app_firefox_prefs,
+                    fl!("firefox-prefs", "key"),
+                    fl!("firefox-prefs", "value"),
+                    Message::FirefoxPref,
+                ))
+                .push(
+                    text(fl!(
+                        "firefox-prefs",
+                        "defaults",
+                        path = firefox_defaults_location().display().to_string()
+                    ))
+                    .size(12),
+                );
+        }
+
+        Scrollable::new(Container::new(view_column).max_width(1000)).into()
+    }
+}
+
+fn update_key_values(list: &mut Vec<(String, String)>, message: KeyValue) {
+    match message {
+        KeyValue::Add => list.push((String::new(), String::new())),
+        KeyValue::Remove(idx) => {
+            if idx < list.len() {
+                list.remove(idx);
+            }
+        }
+        KeyValue::Key(idx, key) => {
+            if let Some(entry) = list.get_mut(idx) {
+                entry.0 = key;
+            }
+        }
+        KeyValue::Value(idx, value) => {
+            if let Some(entry) = list.get_mut(idx) {
+                entry.1 = value;
+            }
+        }
+    }
+}
+
+fn key_values_view<'a>(
+    list: &'a [(String, String)],
+    key_placeholder: String,
+    value_placeholder: String,
+    on_change: fn(KeyValue) -> Message,
+) -> Element<'a, pages::Message> {
+    let mut column = widget::column().spacing(10);
+
+    for (idx, (key, value)) in list.iter().enumerate() {
+        let row = widget::row()
+            .push(
+                widget::text_input(key_placeholder.clone(), key)
+                    .on_input(move |s| pages::Message::Creator(on_change(KeyValue::Key(idx, s))))
+                    .width(Length::Fill),
+            )
+            .push(
+                widget::text_input(value_placeholder.clone(), value)
+                    .on_input(move |s| pages::Message::Creator(on_change(KeyValue::Value(idx, s))))
+                    .width(Length::Fill),
+            )
+            .push(
+                widget::button(icon_cache_get("edit-delete-symbolic", 16))
+                    .on_press(pages::Message::Creator(on_change(KeyValue::Remove(idx))))
+                    .padding(8)
+                    .style(style::Button::Icon),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        column = column.push(row);
     }
+
+    column
+        .push(
+            widget::button(text(fl!("add")))
+                .on_press(pages::Message::Creator(on_change(KeyValue::Add))),
+        )
+        .into()
 }
@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use cosmic::{
+    iced::{Alignment, Length},
+    theme,
+    widget::{self, text, toggler, Container},
+    Element,
+};
+
+use crate::{common::WebAppLauncher, fl, pages};
+
+#[derive(Debug, Clone)]
+pub struct Delete {
+    pub launcher: WebAppLauncher,
+    pub profile: Option<PathBuf>,
+    pub icon: Option<PathBuf>,
+    pub keep_profile: bool,
+    pub busy: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    KeepProfile(bool),
+}
+
+impl Delete {
+    pub fn new(launcher: WebAppLauncher) -> Self {
+        Self {
+            profile: launcher.removable_profile(),
+            icon: launcher.removable_icon(),
+            launcher,
+            keep_profile: false,
+            busy: false,
+        }
+    }
+
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::KeepProfile(keep) => self.keep_profile = keep,
+        }
+    }
+
+    pub fn view(&self) -> Element<pages::Message> {
+        let mut paths = widget::column()
+            .spacing(5)
+            .push(text(self.launcher.path.display().to_string()).size(12));
+
+        if let Some(profile) = &self.profile {
+            if !self.keep_profile {
+                paths = paths.push(text(profile.display().to_string()).size(12));
+            }
+        }
+
+        if let Some(icon) = &self.icon {
+            paths = paths.push(text(icon.display().to_string()).size(12));
+        }
+
+        let mut column = widget::column()
+            .spacing(10)
+            .push(text(fl!("delete", "header", name = self.launcher.name.clone())).size(20))
+            .push(text(fl!("delete", "paths")))
+            .push(paths);
+
+        if self.profile.is_some() {
+            column = column.push(
+                toggler(fl!("delete", "keep-profile"), self.keep_profile, |b| {
+                    pages::Message::Delete(Message::KeepProfile(b))
+                })
+                .width(Length::Fill),
+            );
+        }
+
+        if self.busy {
+            column = column.push(text(fl!("delete", "busy")));
+        }
+
+        let mut delete = widget::button(Container::new(text(fl!("delete"))).center_x())
+            .width(Length::Fill)
+            .style(theme::Button::Destructive);
+
+        if !self.busy {
+            delete = delete.on_press(pages::Message::DoneDelete);
+        }
+
+        let buttons = widget::row()
+            .push(delete)
+            .push(
+                widget::button(Container::new(text(fl!("close"))).center_x())
+                    .on_press(pages::Message::OpenHome)
+                    .width(Length::Fill),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        Container::new(column.push(buttons))
+            .padding(30)
+            .max_width(1000)
+            .into()
+    }
+}
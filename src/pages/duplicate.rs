@@ -0,0 +1,97 @@
+use cosmic::{
+    iced::{Alignment, Length},
+    theme,
+    widget::{self, text, toggler, warning, Container},
+    Element,
+};
+
+use crate::{common::WebAppLauncher, fl, pages};
+
+#[derive(Debug, Clone)]
+pub struct Duplicate {
+    pub launcher: WebAppLauncher,
+    pub name: String,
+    pub copy_profile: bool,
+    pub busy: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Name(String),
+    CopyProfile(bool),
+}
+
+impl Duplicate {
+    pub fn new(launcher: WebAppLauncher) -> Self {
+        let name = fl!("duplicate", "name", name = launcher.name.clone());
+
+        Self {
+            launcher,
+            name,
+            copy_profile: false,
+            busy: false,
+        }
+    }
+
+    pub fn update(&mut self, message: Message) {
+        match message {
+            Message::Name(name) => self.name = name,
+            Message::CopyProfile(copy) => self.copy_profile = copy,
+        }
+    }
+
+    pub fn view(&self, warnings: String) -> Element<pages::Message> {
+        let name = widget::text_input(fl!("title"), &self.name)
+            .on_input(|s| pages::Message::Duplicate(Message::Name(s)))
+            .width(Length::Fill);
+
+        let mut column = widget::column()
+            .spacing(10)
+            .push(warning(warnings))
+            .push(
+                text(fl!(
+                    "duplicate",
+                    "header",
+                    name = self.launcher.name.clone()
+                ))
+                .size(20),
+            )
+            .push(name);
+
+        if self.launcher.profile_path().is_some() {
+            column = column.push(
+                toggler(fl!("duplicate", "copy-profile"), self.copy_profile, |b| {
+                    pages::Message::Duplicate(Message::CopyProfile(b))
+                })
+                .width(Length::Fill),
+            );
+        }
+
+        if self.busy {
+            column = column.push(text(fl!("duplicate", "copying")));
+        }
+
+        let mut duplicate = widget::button(Container::new(text(fl!("duplicate"))).center_x())
+            .width(Length::Fill)
+            .style(theme::Button::Suggested);
+
+        if !self.busy {
+            duplicate = duplicate.on_press(pages::Message::DoneDuplicate);
+        }
+
+        let buttons = widget::row()
+            .push(duplicate)
+            .push(
+                widget::button(Container::new(text(fl!("close"))).center_x())
+                    .on_press(pages::Message::OpenHome)
+                    .width(Length::Fill),
+            )
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        Container::new(column.push(buttons))
+            .padding(30)
+            .max_width(1000)
+            .into()
+    }
+}
@@ -1,5 +1,7 @@
+use std::{collections::HashMap, path::PathBuf};
+
 use crate::{
-    common::{get_webapps, icon_cache_get, WebAppLauncher},
+    common::{format_size, get_webapps, icon_cache_get, WebAppLauncher},
     fl,
     pages::{Buttons, Message},
 };
@@ -17,6 +19,7 @@ use cosmic::{
 pub struct Home {
     pub edit_mode: bool,
     pub launcher: Option<WebAppLauncher>,
+    pub profile_sizes: HashMap<PathBuf, u64>,
 }
 
 impl Home {
@@ -24,6 +27,7 @@ impl Home {
         Home {
             edit_mode: false,
             launcher: None,
+            profile_sizes: HashMap::new(),
         }
     }
 
@@ -31,7 +35,7 @@ impl Home {
         let mut app_list = column().spacing(12).align_items(Alignment::Center);
         let webapps = get_webapps();
 
-        for app in webapps.iter().flatten() {
+        for (app, is_account) in grouped_by_account(webapps.iter().flatten().collect()) {
             let used_browser =
                 Container::new(inline_input(app.web_browser.name.clone()).width(120));
 
@@ -42,21 +46,72 @@ impl Home {
                 .padding(8)
                 .style(style::Button::Icon);
 
+            let add_account = widget::button(icon_cache_get("list-add-symbolic", 16))
+                .on_press(Message::Clicked(Buttons::AddAccount(app.clone())))
+                .padding(8)
+                .style(style::Button::Icon);
+
+            let duplicate = widget::button(icon_cache_get("edit-copy-symbolic", 16))
+                .on_press(Message::Clicked(Buttons::Duplicate(app.clone())))
+                .padding(8)
+                .style(style::Button::Icon);
+
             let delete = widget::button(icon_cache_get("edit-delete-symbolic", 16))
                 .on_press(Message::Clicked(Buttons::Delete(app.clone())))
                 .padding(8)
                 .style(style::Button::Icon);
 
+            let profile_size = app
+                .profile_path()
+                .and_then(|path| self.profile_sizes.get(&path))
+                .map(|size| format_size(*size))
+                .unwrap_or_default();
+
             let row = cosmic::widget::row()
                 .spacing(10)
+                .align_items(Alignment::Center)
                 .push(used_browser)
-                .push(app_name);
-            let row2 = cosmic::widget::row().spacing(10).push(edit).push(delete);
+                .push(app_name)
+                .push(text(profile_size).width(80));
+            let mut row2 = cosmic::widget::row()
+                .spacing(10)
+                .push(edit)
+                .push(add_account)
+                .push(duplicate);
+
+            if app.can_clean_profile() {
+                let clear_cache = widget::tooltip(
+                    widget::button(icon_cache_get("edit-clear-symbolic", 16))
+                        .on_press(Message::Clicked(Buttons::ClearCache(app.clone())))
+                        .padding(8)
+                        .style(style::Button::Icon),
+                    fl!("clear-cache"),
+                    widget::tooltip::Position::Bottom,
+                );
 
-            app_list = app_list.push(
-                Container::new(cosmic::widget::row().push(row).push(row2))
-                    .style(theme::Container::List),
-            );
+                let clear_site_data = widget::tooltip(
+                    widget::button(icon_cache_get("edit-clear-all-symbolic", 16))
+                        .on_press(Message::Clicked(Buttons::ClearSiteData(app.clone())))
+                        .padding(8)
+                        .style(style::Button::Icon),
+                    fl!("clear-site-data"),
+                    widget::tooltip::Position::Bottom,
+                );
+
+                row2 = row2.push(clear_cache).push(clear_site_data);
+            }
+
+            let row2 = row2.push(delete);
+
+            let item = Container::new(cosmic::widget::row().push(row).push(row2))
+                .style(theme::Container::List);
+
+            // further accounts of a site are indented below the app they were added to
+            app_list = if is_account {
+                app_list.push(Container::new(item).padding([0, 0, 0, 30]))
+            } else {
+                app_list.push(item)
+            };
         }
 
         let mut final_content = column().spacing(20).align_items(Alignment::Center);
@@ -73,3 +128,25 @@ impl Home {
             .into()
     }
 }
+
+/// Orders web apps so the accounts of a site follow the app they were added to,
+/// flagging those accounts.
+fn grouped_by_account(webapps: Vec<&WebAppLauncher>) -> Vec<(&WebAppLauncher, bool)> {
+    let is_account = |app: &WebAppLauncher| {
+        !app.account_of.is_empty() && webapps.iter().any(|root| root.codename == app.account_of)
+    };
+
+    let mut grouped = Vec::new();
+
+    for app in webapps.iter().filter(|app| !is_account(app)) {
+        grouped.push((*app, false));
+        grouped.extend(
+            webapps
+                .iter()
+                .filter(|account| is_account(account) && account.account_of == app.codename)
+                .map(|account| (*account, true)),
+        );
+    }
+
+    grouped
+}
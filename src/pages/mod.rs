@@ -1,12 +1,18 @@
+pub mod account;
+pub mod clear_site_data;
 pub mod creator;
+pub mod delete;
+pub mod duplicate;
 pub mod home_screen;
 pub mod iconpicker;
 pub mod icons_installator;
+pub mod recently_deleted;
+pub mod settings;
 
-use std::process::ExitStatus;
+use std::{collections::HashMap, path::PathBuf, process::ExitStatus};
 
 use cosmic::iced::alignment::Horizontal;
-use cosmic::iced::Length;
+use cosmic::iced::{Alignment, Length};
 use cosmic::widget::Container;
 use cosmic::{
     app::{
@@ -15,7 +21,7 @@ use cosmic::{
     },
     cosmic_theme, executor,
     iced::window,
-    style,
+    style, theme,
     widget::{self, text},
     Application, ApplicationExt, Command, Element,
 };
@@ -25,13 +31,20 @@ use crate::{
     add_icon_packs_install_script,
     common::{
         self, find_icon, find_icons, get_icon_name_from_url, get_supported_browsers,
-        icon_cache_get, image_handle, move_icon, my_icons_location, Browser, BrowserType,
-        WebAppLauncher,
+        icon_cache_get, image_handle, move_icon, my_icons_location, profile_sizes, run_blocking,
+        Browser, BrowserType, ProfileMigration, WebAppLauncher,
     },
     execute_script, fl, icon_pack_installed,
+    pages::account::AddAccount,
+    pages::clear_site_data::ClearSiteData,
+    pages::delete::Delete,
+    pages::duplicate::Duplicate,
     pages::home_screen::Home,
     pages::iconpicker::IconPicker,
     pages::icons_installator::Installator,
+    pages::recently_deleted::RecentlyDeleted,
+    pages::settings::Settings,
+    trash::{purge_expired, trash, TrashEntry},
     warning::WarnMessages,
     warning::{WarnAction, Warning},
 };
@@ -40,6 +53,10 @@ use crate::{
 pub enum Buttons {
     SearchFavicon,
     Edit(WebAppLauncher),
+    Duplicate(WebAppLauncher),
+    AddAccount(WebAppLauncher),
+    ClearCache(WebAppLauncher),
+    ClearSiteData(WebAppLauncher),
     Delete(WebAppLauncher),
 }
 
@@ -48,13 +65,35 @@ pub enum Buttons {
 pub enum Message {
     OpenHome,
     OpenCreator,
+    OpenSettings,
+    OpenRecentlyDeleted,
     CloseCreator,
     OpenIconPicker,
     OpenIconPickerDialog,
     DialogIconPicker(DialogMessage),
     OpenFileResult(DialogResult),
+    OpenDownloadDirDialog,
+    DialogDownloadDir(DialogMessage),
+    DownloadDirResult(DialogResult),
     Creator(creator::Message),
+    Settings(settings::Message),
+    Duplicate(duplicate::Message),
+    DoneDuplicate,
+    Duplicated(Result<WebAppLauncher, String>),
+    AddAccount(account::Message),
+    DoneAddAccount,
+    DoneClearSiteData,
+    SiteDataCleared(Result<(), String>),
+    Delete(delete::Message),
+    DoneDelete,
+    Trashed(Result<TrashEntry, String>),
+    RecentlyDeleted(recently_deleted::Message),
+    Undo,
+    Restored(Result<(), String>),
+    DismissUndo,
+    ProfileSizes(HashMap<PathBuf, u64>),
     DoneEdit,
+    ProfileMigrated(Result<(WebAppLauncher, WebAppLauncher, bool), String>),
     DoneCreate,
     LoadingDone,
 
@@ -82,6 +121,12 @@ pub enum Pages {
     AppCreator,
     IconPicker,
     IconInstallator(Installator),
+    Settings,
+    Duplicate(Duplicate),
+    AddAccount(AddAccount),
+    ClearSiteData(ClearSiteData),
+    Delete(Delete),
+    RecentlyDeleted(RecentlyDeleted),
 }
 
 pub struct Window {
@@ -90,6 +135,8 @@ pub struct Window {
     current_page: Pages,
     creator_window: creator::AppCreator,
     icon_selector: IconPicker,
+    settings: Settings,
+    undo: Option<TrashEntry>,
     dialog_opt: Option<Dialog<Message>>,
     warning: Warning,
 }
@@ -136,11 +183,21 @@ impl Application for Window {
             current_page: page,
             creator_window: creator,
             icon_selector: selector,
+            settings: Settings::new(),
+            undo: None,
             dialog_opt: None,
             warning: warn_element,
         };
 
-        let commands = Command::batch(vec![windows.set_title(), cmd]);
+        let sizes = Command::perform(profile_sizes(), |sizes| app(Message::ProfileSizes(sizes)));
+        let expired = Command::perform(run_blocking(purge_expired), |result| {
+            if let Err(e) = result {
+                tracing::error!("cant purge expired web apps from trash: {}", e);
+            }
+
+            message::none()
+        });
+        let commands = Command::batch(vec![windows.set_title(), cmd, sizes, expired]);
 
         (windows, commands)
     }
@@ -148,6 +205,8 @@ impl Application for Window {
     fn header_start(&self) -> Vec<Element<Self::Message>> {
         let go_home_icon = icon_cache_get("go-home-symbolic", 16);
         let go_creator = icon_cache_get("document-new-symbolic", 16);
+        let go_settings = icon_cache_get("preferences-system-symbolic", 16);
+        let go_trash = icon_cache_get("user-trash-symbolic", 16);
         let cosmic_theme::Spacing { space_xxs, .. } = self.core().system_theme().cosmic().spacing;
 
         vec![
@@ -161,6 +220,16 @@ impl Application for Window {
                 .padding(space_xxs)
                 .style(style::Button::Icon)
                 .into(),
+            widget::button(go_trash)
+                .on_press(Message::OpenRecentlyDeleted)
+                .padding(space_xxs)
+                .style(style::Button::Icon)
+                .into(),
+            widget::button(go_settings)
+                .on_press(Message::OpenSettings)
+                .padding(space_xxs)
+                .style(style::Button::Icon)
+                .into(),
         ]
     }
 
@@ -177,6 +246,12 @@ impl Application for Window {
             }
             Pages::IconPicker => vec![text(fl!("icon-selector")).into()],
             Pages::IconInstallator(_) => vec![text(fl!("icon-installer")).into()],
+            Pages::Settings => vec![text(fl!("settings")).into()],
+            Pages::Duplicate(_) => vec![text(fl!("duplicate")).into()],
+            Pages::AddAccount(_) => vec![text(fl!("add-account")).into()],
+            Pages::ClearSiteData(_) => vec![text(fl!("clear-site-data")).into()],
+            Pages::Delete(_) => vec![text(fl!("delete")).into()],
+            Pages::RecentlyDeleted(_) => vec![text(fl!("recently-deleted")).into()],
         }
     }
 
@@ -185,15 +260,27 @@ impl Application for Window {
             Message::OpenHome => {
                 self.current_page = Pages::MainWindow;
 
-                Command::none()
+                Command::perform(profile_sizes(), |sizes| app(Message::ProfileSizes(sizes)))
             }
 
             Message::OpenCreator => {
                 self.current_page = Pages::AppCreator;
+                self.creator_window.app_account_of.clear();
+                self.creator_window.refresh_profiles();
                 self.init_warning_box();
 
                 Command::none()
             }
+            Message::OpenRecentlyDeleted => {
+                self.current_page = Pages::RecentlyDeleted(RecentlyDeleted::new());
+
+                Command::none()
+            }
+            Message::OpenSettings => {
+                self.current_page = Pages::Settings;
+
+                Command::none()
+            }
             Message::CloseCreator => {
                 self.current_page = Pages::MainWindow;
                 self.creator_window.edit_mode = false;
@@ -205,6 +292,190 @@ impl Application for Window {
 
                 command.map(|mess| mess)
             }
+            Message::Settings(message) => self.settings.update(message),
+            Message::Duplicate(message) => {
+                if let Pages::Duplicate(duplicate) = &mut self.current_page {
+                    duplicate.update(message);
+                }
+
+                Command::none()
+            }
+            Message::ProfileSizes(sizes) => {
+                self.main_window.profile_sizes = sizes;
+
+                Command::none()
+            }
+            Message::Delete(message) => {
+                if let Pages::Delete(delete) = &mut self.current_page {
+                    delete.update(message);
+                }
+
+                Command::none()
+            }
+            Message::DoneDelete => {
+                if let Pages::Delete(delete) = &mut self.current_page {
+                    if delete.busy {
+                        return Command::none();
+                    }
+
+                    // the profile is copied when the trash is on another file system
+                    let launcher = delete.launcher.clone();
+                    let keep_profile = delete.keep_profile;
+                    delete.busy = true;
+
+                    return Command::perform(
+                        run_blocking(move || trash(&launcher, keep_profile)),
+                        |result| app(Message::Trashed(result.map_err(|e| e.to_string()))),
+                    );
+                }
+
+                Command::none()
+            }
+            Message::Trashed(result) => {
+                match result {
+                    Ok(entry) => self.undo = Some(entry),
+                    Err(e) => tracing::error!("cant move web app to trash: {}", e),
+                }
+
+                if let Pages::Delete(_) = self.current_page {
+                    self.current_page = Pages::MainWindow;
+                }
+
+                Command::perform(profile_sizes(), |sizes| app(Message::ProfileSizes(sizes)))
+            }
+            Message::RecentlyDeleted(message) => {
+                if let Pages::RecentlyDeleted(recently_deleted) = &mut self.current_page {
+                    return recently_deleted.update(message);
+                }
+
+                Command::none()
+            }
+            Message::Undo => match self.undo.take() {
+                Some(entry) => Command::perform(run_blocking(move || entry.restore()), |result| {
+                    app(Message::Restored(result.map_err(|e| e.to_string())))
+                }),
+                None => Command::none(),
+            },
+            Message::Restored(result) => {
+                if let Err(e) = result {
+                    tracing::error!("cant restore web app: {}", e);
+                }
+
+                Command::perform(profile_sizes(), |sizes| app(Message::ProfileSizes(sizes)))
+            }
+            Message::DismissUndo => {
+                self.undo = None;
+
+                Command::none()
+            }
+            Message::AddAccount(message) => {
+                if let Pages::AddAccount(add_account) = &mut self.current_page {
+                    add_account.update(message);
+                }
+
+                Command::none()
+            }
+            Message::DoneAddAccount => {
+                if let Pages::AddAccount(add_account) = &self.current_page {
+                    match add_account
+                        .launcher
+                        .add_account(add_account.account.trim(), add_account.badge)
+                    {
+                        Ok(launcher) if launcher.is_valid => match launcher.create() {
+                            Ok(()) => self.current_page = Pages::MainWindow,
+                            Err(e) => {
+                                tracing::error!("cant save web app: {}", e);
+                                self.warning.push_warn(WarnMessages::SaveFailed);
+                            }
+                        },
+                        Ok(_) => self.warning.push_warn(WarnMessages::Duplicate),
+                        Err(e) => {
+                            tracing::error!("cant add account: {}", e);
+                            self.warning.push_warn(WarnMessages::SaveFailed);
+                        }
+                    }
+                }
+
+                Command::none()
+            }
+            Message::DoneDuplicate => {
+                if let Pages::Duplicate(duplicate) = &mut self.current_page {
+                    if duplicate.busy {
+                        return Command::none();
+                    }
+
+                    // copying the profile can take a while
+                    let launcher = duplicate.launcher.clone();
+                    let name = duplicate.name.clone();
+                    let copy_profile = duplicate.copy_profile;
+                    duplicate.busy = true;
+
+                    return Command::perform(
+                        run_blocking(move || launcher.duplicate(name, copy_profile)),
+                        |result| app(Message::Duplicated(result.map_err(|e| e.to_string()))),
+                    );
+                }
+
+                Command::none()
+            }
+            Message::Duplicated(result) => {
+                let on_page = match &mut self.current_page {
+                    Pages::Duplicate(duplicate) => {
+                        duplicate.busy = false;
+                        true
+                    }
+                    _ => false,
+                };
+
+                match result {
+                    Ok(launcher) if launcher.is_valid => match launcher.create() {
+                        Ok(()) if on_page => self.current_page = Pages::MainWindow,
+                        Ok(()) => {}
+                        Err(e) => {
+                            tracing::error!("cant save web app: {}", e);
+                            self.warning.push_warn(WarnMessages::SaveFailed);
+                        }
+                    },
+                    Ok(_) => self.warning.push_warn(WarnMessages::Duplicate),
+                    Err(e) => {
+                        tracing::error!("cant duplicate web app: {}", e);
+                        self.warning.push_warn(WarnMessages::SaveFailed);
+                    }
+                }
+
+                Command::none()
+            }
+            Message::DoneClearSiteData => {
+                if let Pages::ClearSiteData(clear_site_data) = &mut self.current_page {
+                    // the browser may have been started since the page was opened
+                    clear_site_data.in_use = clear_site_data.launcher.profile_in_use();
+
+                    if clear_site_data.in_use || clear_site_data.busy {
+                        return Command::none();
+                    }
+
+                    let launcher = clear_site_data.launcher.clone();
+                    clear_site_data.busy = true;
+
+                    return Command::perform(
+                        run_blocking(move || launcher.clear_site_data()),
+                        |result| app(Message::SiteDataCleared(result.map_err(|e| e.to_string()))),
+                    );
+                }
+
+                Command::none()
+            }
+            Message::SiteDataCleared(result) => {
+                if let Err(e) = result {
+                    tracing::error!("cant clear site data: {}", e);
+                }
+
+                if let Pages::ClearSiteData(_) = self.current_page {
+                    self.current_page = Pages::MainWindow;
+                }
+
+                Command::perform(profile_sizes(), |sizes| app(Message::ProfileSizes(sizes)))
+            }
             Message::Warning((action, message)) => {
                 match action {
                     WarnAction::Add => self.warning.push_warn(message),
@@ -262,22 +533,48 @@ impl Application for Window {
                 }
                 Command::none()
             }
+            Message::OpenDownloadDirDialog => {
+                if self.dialog_opt.is_none() {
+                    let (dialog, command) = Dialog::new(
+                        DialogKind::OpenFolder,
+                        None,
+                        Message::DialogDownloadDir,
+                        Message::DownloadDirResult,
+                    );
+                    self.dialog_opt = Some(dialog);
+                    return command;
+                }
+                Command::none()
+            }
+            Message::DialogDownloadDir(message) => {
+                if let Some(dialog) = &mut self.dialog_opt {
+                    return dialog.update(message);
+                }
+                Command::none()
+            }
+            Message::DownloadDirResult(result) => {
+                self.dialog_opt = None;
+
+                if let DialogResult::Open(paths) = result {
+                    if let Some(path) = paths.first().and_then(|path| path.to_str()) {
+                        self.creator_window.app_download_dir = path.to_string();
+                    }
+                }
+                Command::none()
+            }
             Message::DoneCreate => {
-                let new_entry = WebAppLauncher::new(
-                    self.creator_window.app_title.clone(),
-                    None,
-                    self.creator_window.app_url.clone(),
-                    self.creator_window.app_icon.clone(),
-                    self.creator_window.app_category.clone(),
-                    self.creator_window.app_browser.clone(),
-                    self.creator_window.app_parameters.clone(),
-                    self.creator_window.app_isolated,
-                    self.creator_window.app_navbar,
-                    self.creator_window.app_incognito,
-                );
+                // the creator already shows what is wrong
+                if !self.creator_window.options_valid() {
+                    return Command::none();
+                }
+
+                let new_entry = self.launcher_from_creator(None);
 
                 if new_entry.is_valid {
-                    self.create_valid_launcher(new_entry).unwrap();
+                    if let Err(e) = self.create_valid_launcher(new_entry) {
+                        tracing::error!("cant save web app: {}", e);
+                        self.warning.push_warn(WarnMessages::SaveFailed);
+                    }
                 } else {
                     self.warning.push_warn(WarnMessages::Duplicate);
                 }
@@ -285,23 +582,33 @@ impl Application for Window {
                 Command::none()
             }
             Message::DoneEdit => {
+                if !self.creator_window.options_valid() || self.creator_window.busy {
+                    return Command::none();
+                }
+
                 if let Some(launcher) = self.main_window.launcher.to_owned() {
-                    let _deleted = launcher.delete();
-                    let edited_entry = WebAppLauncher::new(
-                        self.creator_window.app_title.clone(),
-                        Some(launcher.codename),
-                        self.creator_window.app_url.clone(),
-                        self.creator_window.app_icon.clone(),
-                        self.creator_window.app_category.clone(),
-                        self.creator_window.app_browser.clone(),
-                        self.creator_window.app_parameters.clone(),
-                        self.creator_window.app_isolated,
-                        self.creator_window.app_navbar,
-                        self.creator_window.app_incognito,
-                    );
+                    let edited_entry = self.launcher_from_creator(Some(launcher.codename.clone()));
 
                     if edited_entry.is_valid {
-                        self.create_valid_launcher(edited_entry).unwrap();
+                        let migration = self.creator_window.app_profile_migration;
+                        self.creator_window.busy = true;
+
+                        // the old launcher is replaced only once the new one is written
+                        return Command::perform(
+                            run_blocking(move || {
+                                let remove_old_profile = edited_entry
+                                    .migrate_profile(&launcher, migration)
+                                    .unwrap_or_else(|e| {
+                                        tracing::error!("cant migrate profile: {}", e);
+                                        false
+                                    });
+
+                                Ok((edited_entry, launcher, remove_old_profile))
+                            }),
+                            |result| {
+                                app(Message::ProfileMigrated(result.map_err(|e| e.to_string())))
+                            },
+                        );
                     } else {
                         self.warning.push_warn(WarnMessages::Duplicate);
                     }
@@ -309,6 +616,37 @@ impl Application for Window {
                 self.creator_window.edit_mode = false;
                 Command::none()
             }
+            Message::ProfileMigrated(result) => {
+                self.creator_window.busy = false;
+
+                let (edited_entry, launcher, remove_old_profile) = match result {
+                    Ok(migrated) => migrated,
+                    Err(e) => {
+                        tracing::error!("cant migrate profile: {}", e);
+                        self.warning.push_warn(WarnMessages::SaveFailed);
+                        return Command::none();
+                    }
+                };
+
+                match self.create_valid_launcher(edited_entry) {
+                    Ok(()) if remove_old_profile => Command::perform(
+                        run_blocking(move || launcher.delete_profile()),
+                        |result| {
+                            if let Err(e) = result {
+                                tracing::error!("cant remove old profile: {}", e);
+                            }
+
+                            message::none()
+                        },
+                    ),
+                    Ok(()) => Command::none(),
+                    Err(e) => {
+                        tracing::error!("cant save web app: {}", e);
+                        self.warning.push_warn(WarnMessages::SaveFailed);
+                        Command::none()
+                    }
+                }
+            }
             Message::Clicked(buttons) => match buttons {
                 Buttons::Edit(launcher) => {
                     let selected_browser = get_supported_browsers()
@@ -328,15 +666,64 @@ impl Application for Window {
                         Browser::web_browser(launcher.web_browser.name).expect("browser not found");
                     self.creator_window.selected_browser = selected_browser;
                     self.creator_window.app_navbar = launcher.navbar;
+                    self.creator_window.app_isolated = launcher.isolate_profile;
                     self.creator_window.app_incognito = launcher.is_incognito;
+                    self.creator_window.app_display_mode = launcher.display_mode;
+                    self.creator_window.app_color_scheme = launcher.color_scheme;
+                    self.creator_window.app_display_backend = launcher.display_backend;
+                    self.creator_window.app_zoom = launcher.zoom;
+                    self.creator_window.app_language = launcher.language.clone();
+                    self.creator_window.app_download_dir = launcher.download_dir.clone();
+                    self.creator_window.app_session_policy = launcher.session_policy;
+                    self.creator_window.app_env_vars = launcher.env_vars.clone();
+                    self.creator_window.app_profile_group = launcher.profile_group.clone();
+                    self.creator_window.app_browser_profile = launcher.browser_profile.clone();
+                    self.creator_window.app_account_of = launcher.account_of.clone();
+                    self.creator_window.app_new_profile_group = false;
+                    self.creator_window.app_profile_migration = ProfileMigration::Move;
+                    self.creator_window.refresh_profiles();
+                    self.creator_window
+                        .set_window_geometry(launcher.window_size, launcher.window_position);
+                    self.creator_window.app_extensions = launcher.extensions.join("; ");
+                    self.creator_window.app_user_agent = launcher.user_agent;
+                    self.creator_window.app_proxy = launcher.proxy;
+                    self.creator_window.app_proxy_bypass = launcher.proxy_bypass;
+                    self.creator_window.app_firefox_prefs = launcher.firefox_prefs;
+                    self.creator_window.app_user_chrome_css = launcher.user_chrome_css;
+                    self.creator_window.app_theme_colors = launcher.theme_colors;
+                    self.creator_window.app_user_content_css = launcher.user_content_css;
                     self.creator_window.edit_mode = true;
 
                     Command::perform(image_handle(launcher.icon), |result| {
                         app(Message::SetIcon(result.unwrap()))
                     })
                 }
+                Buttons::AddAccount(launcher) => {
+                    self.warning.remove_all_warns();
+                    self.current_page = Pages::AddAccount(AddAccount::new(launcher));
+
+                    Command::none()
+                }
+                Buttons::Duplicate(launcher) => {
+                    self.warning.remove_all_warns();
+                    self.current_page = Pages::Duplicate(Duplicate::new(launcher));
+
+                    Command::none()
+                }
+                Buttons::ClearCache(launcher) => {
+                    if let Err(e) = launcher.clear_cache() {
+                        tracing::error!("cant clear cache: {}", e);
+                    }
+
+                    Command::perform(profile_sizes(), |sizes| app(Message::ProfileSizes(sizes)))
+                }
+                Buttons::ClearSiteData(launcher) => {
+                    self.current_page = Pages::ClearSiteData(ClearSiteData::new(launcher));
+
+                    Command::none()
+                }
                 Buttons::Delete(launcher) => {
-                    let _ = launcher.delete();
+                    self.current_page = Pages::Delete(Delete::new(launcher));
 
                     Command::none()
                 }
@@ -503,6 +890,49 @@ impl Application for Window {
             Pages::AppCreator => self.creator_window.view(self.warning.messages()),
             Pages::IconPicker => self.icon_selector.view(),
             Pages::IconInstallator(installator) => installator.view(),
+            Pages::Settings => self.settings.view(),
+            Pages::Duplicate(duplicate) => duplicate.view(self.warning.messages()),
+            Pages::AddAccount(add_account) => add_account.view(self.warning.messages()),
+            Pages::ClearSiteData(clear_site_data) => clear_site_data.view(),
+            Pages::Delete(delete) => delete.view(),
+            Pages::RecentlyDeleted(recently_deleted) => recently_deleted.view(),
+        };
+
+        let view = match (&self.current_page, &self.undo) {
+            (Pages::MainWindow, Some(entry)) => {
+                let banner = widget::row()
+                    .push(
+                        text(fl!(
+                            "recently-deleted",
+                            "undo-message",
+                            name = entry.name.clone()
+                        ))
+                        .width(Length::Fill),
+                    )
+                    .push(
+                        widget::button(text(fl!("recently-deleted", "undo")))
+                            .on_press(Message::Undo),
+                    )
+                    .push(
+                        widget::button(icon_cache_get("window-close-symbolic", 16))
+                            .on_press(Message::DismissUndo)
+                            .style(style::Button::Icon),
+                    )
+                    .align_items(Alignment::Center)
+                    .spacing(10);
+
+                widget::column()
+                    .push(
+                        Container::new(banner)
+                            .padding(10)
+                            .max_width(1000)
+                            .style(theme::Container::List),
+                    )
+                    .push(view)
+                    .align_items(Alignment::Center)
+                    .into()
+            }
+            _ => view,
         };
 
         Container::new(view)
@@ -525,13 +955,54 @@ impl Window {
     fn set_title(&mut self) -> Command<CosmicMessage<Message>> {
         self.set_window_title(fl!("app"), self.main_window_id())
     }
+    fn launcher_from_creator(&self, codename: Option<String>) -> WebAppLauncher {
+        WebAppLauncher::new(
+            self.creator_window.app_title.clone(),
+            codename,
+            self.creator_window.app_url.clone(),
+            self.creator_window.app_icon.clone(),
+            self.creator_window.app_category.clone(),
+            self.creator_window.app_browser.clone(),
+            self.creator_window.app_parameters.clone(),
+            self.creator_window.app_isolated,
+            self.creator_window.app_navbar,
+            self.creator_window.app_incognito,
+            self.creator_window.app_display_mode,
+            self.creator_window.window_size(),
+            self.creator_window.window_position(),
+            self.creator_window.extensions(),
+            self.creator_window.app_user_agent.trim().to_string(),
+            self.creator_window.app_proxy.trim().to_string(),
+            self.creator_window.app_proxy_bypass.trim().to_string(),
+            self.creator_window.firefox_prefs(),
+            self.creator_window.app_user_chrome_css.trim().to_string(),
+            self.creator_window.app_theme_colors,
+            self.creator_window.app_user_content_css.clone(),
+            self.creator_window.app_color_scheme,
+            self.creator_window.app_display_backend,
+            self.creator_window.env_vars(),
+            self.creator_window.app_profile_group.clone(),
+            self.creator_window.app_browser_profile.clone(),
+            self.creator_window.app_account_of.clone(),
+            self.creator_window.app_zoom,
+            self.creator_window.app_language.trim().to_string(),
+            self.creator_window.app_download_dir.trim().to_string(),
+            self.creator_window.app_session_policy,
+        )
+    }
+
     fn create_valid_launcher(&mut self, entry: WebAppLauncher) -> anyhow::Result<()> {
         move_icon(
             self.creator_window.app_icon.clone(),
             self.creator_window.app_title.clone(),
         );
 
-        entry.create().unwrap();
+        entry.create()?;
+
+        if let Err(e) = entry.sync_profile_group() {
+            tracing::error!("cant update profile group: {}", e);
+        }
+
         self.creator_window.edit_mode = false;
         self.current_page = Pages::MainWindow;
 
@@ -0,0 +1,204 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use cosmic::{
+    app::{message::app, Message as CosmicMessage},
+    iced::{Alignment, Length},
+    iced_widget::Scrollable,
+    style, theme,
+    widget::{self, text, Container},
+    Command, Element,
+};
+
+use crate::{
+    common::{icon_cache_get, run_blocking},
+    fl, pages,
+    trash::{trash_entries, TrashEntry, TRASH_EXPIRY_DAYS},
+};
+
+#[derive(Debug, Clone)]
+pub struct RecentlyDeleted {
+    pub entries: Vec<TrashEntry>,
+    pub busy: bool,
+    /// Purge waiting for confirmation.
+    pub confirm: Option<Message>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Restore(usize),
+    Purge(usize),
+    PurgeAll,
+    ConfirmPurge,
+    CancelPurge,
+    Done(Result<(), String>),
+}
+
+impl RecentlyDeleted {
+    pub fn new() -> Self {
+        Self {
+            entries: trash_entries(),
+            busy: false,
+            confirm: None,
+        }
+    }
+
+    pub fn update(&mut self, message: Message) -> Command<CosmicMessage<pages::Message>> {
+        if self.busy && !matches!(message, Message::Done(_)) {
+            return Command::none();
+        }
+
+        let (restore, entries) = match message {
+            // purging can't be undone
+            Message::Purge(_) | Message::PurgeAll => {
+                self.confirm = Some(message);
+                return Command::none();
+            }
+            Message::CancelPurge => {
+                self.confirm = None;
+                return Command::none();
+            }
+            Message::ConfirmPurge => match self.confirm.take() {
+                Some(Message::Purge(idx)) => (false, vec![self.entries[idx].clone()]),
+                Some(Message::PurgeAll) => (false, self.entries.clone()),
+                _ => return Command::none(),
+            },
+            Message::Restore(idx) => (true, vec![self.entries[idx].clone()]),
+            Message::Done(result) => {
+                if let Err(e) = result {
+                    tracing::error!("cant update trash: {}", e);
+                }
+
+                self.busy = false;
+                self.entries = trash_entries();
+
+                return Command::none();
+            }
+        };
+
+        self.busy = true;
+
+        // profiles can be large and may have to be copied between file systems
+        Command::perform(
+            run_blocking(move || {
+                if restore {
+                    entries.iter().try_for_each(TrashEntry::restore)
+                } else {
+                    entries.iter().try_for_each(TrashEntry::purge)
+                }
+            }),
+            |result| {
+                app(pages::Message::RecentlyDeleted(Message::Done(
+                    result.map_err(|e| e.to_string()),
+                )))
+            },
+        )
+    }
+
+    pub fn view(&self) -> Element<pages::Message> {
+        if self.entries.is_empty() {
+            return Container::new(text(fl!("recently-deleted", "empty")).size(20))
+                .padding(30)
+                .into();
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+
+        let mut list = widget::column().spacing(12);
+
+        for (idx, entry) in self.entries.iter().enumerate() {
+            let mut restore = widget::button(icon_cache_get("edit-undo-symbolic", 16))
+                .padding(8)
+                .style(style::Button::Icon);
+
+            let mut purge = widget::button(icon_cache_get("edit-delete-symbolic", 16))
+                .padding(8)
+                .style(style::Button::Icon);
+
+            if !self.busy {
+                restore = restore.on_press(pages::Message::RecentlyDeleted(Message::Restore(idx)));
+                purge = purge.on_press(pages::Message::RecentlyDeleted(Message::Purge(idx)));
+            }
+
+            let row = widget::row()
+                .push(text(entry.name.clone()).width(Length::Fill))
+                .push(text(deleted_ago(now.saturating_sub(entry.deleted))))
+                .push(restore)
+                .push(purge)
+                .align_items(Alignment::Center)
+                .spacing(10);
+
+            list = list.push(Container::new(row).style(theme::Container::List));
+        }
+
+        let mut column = widget::column()
+            .spacing(20)
+            .push(text(fl!(
+                "recently-deleted",
+                "expiry",
+                days = TRASH_EXPIRY_DAYS
+            )))
+            .push(Scrollable::new(list));
+
+        if self.busy {
+            column = column.push(text(fl!("recently-deleted", "busy")));
+        }
+
+        column = match &self.confirm {
+            Some(purge) => {
+                let question = match purge {
+                    Message::Purge(idx) => fl!(
+                        "recently-deleted",
+                        "confirm-purge",
+                        name = self.entries[*idx].name.clone()
+                    ),
+                    _ => fl!("recently-deleted", "confirm-purge-all"),
+                };
+
+                let buttons = widget::row()
+                    .push(
+                        widget::button(
+                            Container::new(text(fl!("recently-deleted", "purge"))).center_x(),
+                        )
+                        .on_press(pages::Message::RecentlyDeleted(Message::ConfirmPurge))
+                        .width(Length::Fill)
+                        .style(theme::Button::Destructive),
+                    )
+                    .push(
+                        widget::button(Container::new(text(fl!("close"))).center_x())
+                            .on_press(pages::Message::RecentlyDeleted(Message::CancelPurge))
+                            .width(Length::Fill),
+                    )
+                    .align_items(Alignment::Center)
+                    .spacing(10);
+
+                column.push(text(question)).push(buttons)
+            }
+            None => {
+                let mut purge_all = widget::button(
+                    Container::new(text(fl!("recently-deleted", "purge-all"))).center_x(),
+                )
+                .style(theme::Button::Destructive);
+
+                if !self.busy {
+                    purge_all =
+                        purge_all.on_press(pages::Message::RecentlyDeleted(Message::PurgeAll));
+                }
+
+                column.push(purge_all)
+            }
+        };
+
+        Container::new(column).padding(30).max_width(1000).into()
+    }
+}
+
+fn deleted_ago(seconds: u64) -> String {
+    match seconds {
+        0..=3599 => fl!("recently-deleted", "minutes", count = seconds / 60),
+        3600..=86399 => fl!("recently-deleted", "hours", count = seconds / 3600),
+        _ => fl!("recently-deleted", "days", count = seconds / 86400),
+    }
+}
@@ -0,0 +1,114 @@
+use cosmic::{
+    app::{message::app, Message as CosmicMessage},
+    iced::{Alignment, Length},
+    widget::{self, dropdown, text, Container},
+    Command, Element,
+};
+
+use crate::{
+    common::{get_webapps, run_blocking, DisplayBackend},
+    config::Config,
+    fl, pages,
+};
+
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub config: Config,
+    pub display_backends: Vec<String>,
+    pub busy: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    DisplayBackend(usize),
+    Updated(Result<(), String>),
+}
+
+impl Settings {
+    pub fn new() -> Self {
+        Self {
+            config: Config::load(),
+            display_backends: DisplayBackend::ALL.iter().map(|b| b.to_string()).collect(),
+            busy: false,
+        }
+    }
+
+    pub fn update(&mut self, message: Message) -> Command<CosmicMessage<pages::Message>> {
+        match message {
+            Message::DisplayBackend(_) if self.busy => Command::none(),
+            Message::DisplayBackend(idx) => {
+                let backend = DisplayBackend::ALL[idx];
+
+                match Config::handler() {
+                    Some(handler) => {
+                        if let Err(e) = self.config.set_display_backend(&handler, backend) {
+                            tracing::error!("cant save settings: {}", e);
+                        }
+                    }
+                    None => self.config.display_backend = backend,
+                }
+
+                self.busy = true;
+
+                // the backend ends up in the Exec line, so apps following the default are
+                // rewritten, which also writes their profile files
+                Command::perform(
+                    run_blocking(|| {
+                        for launcher in get_webapps()
+                            .into_iter()
+                            .flatten()
+                            .filter(|app| app.display_backend == DisplayBackend::Default)
+                        {
+                            if let Err(e) = launcher.create() {
+                                tracing::error!("cant update {}: {}", launcher.name, e);
+                            }
+                        }
+
+                        Ok(())
+                    }),
+                    |result| {
+                        app(pages::Message::Settings(Message::Updated(
+                            result.map_err(|e| e.to_string()),
+                        )))
+                    },
+                )
+            }
+            Message::Updated(result) => {
+                if let Err(e) = result {
+                    tracing::error!("cant update web apps: {}", e);
+                }
+
+                self.busy = false;
+                Command::none()
+            }
+        }
+    }
+
+    pub fn view(&self) -> Element<pages::Message> {
+        let display_backends = dropdown(
+            &self.display_backends,
+            DisplayBackend::ALL
+                .iter()
+                .position(|b| *b == self.config.display_backend),
+            |idx| pages::Message::Settings(Message::DisplayBackend(idx)),
+        )
+        .width(Length::Fixed(200.));
+
+        let display_backend_row = widget::row()
+            .push(text(fl!("display-backend", "global")).width(Length::Fill))
+            .push(display_backends)
+            .align_items(Alignment::Center)
+            .spacing(10);
+
+        let mut column = widget::column()
+            .spacing(10)
+            .push(display_backend_row)
+            .push(text(fl!("display-backend", "global-hint")).size(12));
+
+        if self.busy {
+            column = column.push(text(fl!("display-backend", "updating")));
+        }
+
+        Container::new(column).padding(30).max_width(1000).into()
+    }
+}
@@ -161,6 +161,12 @@ pub fn native_browsers() -> Vec<Browser> {
         ),
         Browser::new(BrowserType::Chromium, "Brave", "brave", "/usr/bin/brave"),
         Browser::new(BrowserType::Falkon, "Falkon", "falkon", "/usr/bin/falkon"),
+        Browser::new(
+            BrowserType::Qutebrowser,
+            "qutebrowser",
+            "qutebrowser",
+            "/usr/bin/qutebrowser",
+        ),
     ]
 }
 
@@ -298,5 +304,17 @@ pub fn flatpak_browsers() -> Vec<Browser> {
             ".local/share/flatpak/exports/bin/ru.yandex.Browser",
             ".local/share/flatpak/exports/bin/ru.yandex.Browser",
         ),
+        Browser::new(
+            BrowserType::Qutebrowser,
+            "qutebrowser",
+            "/var/lib/flatpak/exports/bin/org.qutebrowser.qutebrowser",
+            "/var/lib/flatpak/exports/bin/org.qutebrowser.qutebrowser",
+        ),
+        Browser::new(
+            BrowserType::Qutebrowser,
+            "qutebrowser",
+            ".local/share/flatpak/exports/bin/org.qutebrowser.qutebrowser",
+            ".local/share/flatpak/exports/bin/org.qutebrowser.qutebrowser",
+        ),
     ]
 }
@@ -0,0 +1,80 @@
+use cosmic::{
+    cosmic_config::{self, CosmicConfigEntry},
+    cosmic_theme::{palette::Srgba, Theme},
+};
+
+/// Loads a COSMIC theme, `None` when it isn't fully configured, e.g. inside the sandbox.
+fn cosmic_theme(config: Result<cosmic_config::Config, cosmic_config::Error>) -> Option<Theme> {
+    match Theme::get_entry(&config.ok()?) {
+        Ok(theme) => Some(theme),
+        Err((errors, _)) => {
+            tracing::debug!("cant load COSMIC theme: {:?}", errors);
+            None
+        }
+    }
+}
+
+fn rgb(color: Srgba) -> (u8, u8, u8) {
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    (
+        channel(color.red),
+        channel(color.green),
+        channel(color.blue),
+    )
+}
+
+fn css_color((red, green, blue): (u8, u8, u8)) -> String {
+    format!("rgb({}, {}, {})", red, green, blue)
+}
+
+fn text_color((red, green, blue): (u8, u8, u8)) -> &'static str {
+    let luminance = 0.299 * red as f32 + 0.587 * green as f32 + 0.114 * blue as f32;
+
+    if luminance > 140.0 {
+        "rgb(0, 0, 0)"
+    } else {
+        "rgb(255, 255, 255)"
+    }
+}
+
+fn theme_variables(theme: &Theme) -> String {
+    let accent = rgb(theme.accent_color());
+    let background = rgb(theme.bg_color());
+
+    format!(
+        ":root {{
+    --lwt-accent-color: {background} !important;
+    --lwt-text-color: {text} !important;
+    --toolbar-bgcolor: {background} !important;
+    --toolbar-color: {text} !important;
+    --tab-selected-bgcolor: {accent} !important;
+    --tab-selected-textcolor: {accent_text} !important;
+    --focus-outline-color: {accent} !important;
+    --toolbar-field-focus-border-color: {accent} !important;
+}}
+",
+        background = css_color(background),
+        text = text_color(background),
+        accent = css_color(accent),
+        accent_text = text_color(accent),
+    )
+}
+
+/// Toolbar and tab colours for userChrome.css taken from the light and dark COSMIC themes.
+pub fn cosmic_user_chrome_css() -> String {
+    let mut css = String::from("/* generated from the COSMIC theme */\n");
+
+    if let Some(light) = cosmic_theme(Theme::light_config()) {
+        css.push_str(&theme_variables(&light));
+    }
+
+    if let Some(dark) = cosmic_theme(Theme::dark_config()) {
+        css.push_str(&format!(
+            "@media (prefers-color-scheme: dark) {{\n{}}}\n",
+            theme_variables(&dark)
+        ));
+    }
+
+    css
+}
@@ -0,0 +1,310 @@
+use std::{
+    fs::{self, create_dir_all, remove_dir_all},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{anyhow, Result};
+use walkdir::WalkDir;
+
+use crate::common::{home_dir, write_atomic, WebAppLauncher};
+
+pub fn trash_location() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| home_dir().join(".local/share"))
+        .join("io.github.elevenhsoft.WebApps")
+        .join("trash")
+}
+
+/// Trashed web apps are purged for good after this many days.
+pub const TRASH_EXPIRY_DAYS: u64 = 30;
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default()
+}
+
+/// A deleted web app, kept in the trash until it gets restored or purged.
+#[derive(Debug, Clone)]
+pub struct TrashEntry {
+    pub path: PathBuf,
+    pub name: String,
+    pub deleted: u64,
+    pub launcher: PathBuf,
+    pub icon: Option<PathBuf>,
+    pub profile: Option<PathBuf>,
+}
+
+impl TrashEntry {
+    fn read(path: PathBuf) -> Option<TrashEntry> {
+        let manifest = fs::read_to_string(path.join("manifest")).ok()?;
+
+        let mut name = String::new();
+        let mut deleted = 0;
+        let mut launcher = None;
+        let mut icon = None;
+        let mut profile = None;
+
+        for line in manifest.lines() {
+            if let Some((key, value)) = line.split_once('=') {
+                match key {
+                    "name" => name = value.to_string(),
+                    "deleted" => deleted = value.parse().unwrap_or_default(),
+                    "launcher" => launcher = Some(PathBuf::from(value)),
+                    "icon" => icon = Some(PathBuf::from(value)),
+                    "profile" => profile = Some(PathBuf::from(value)),
+                    _ => {}
+                }
+            }
+        }
+
+        Some(TrashEntry {
+            path,
+            name,
+            deleted,
+            launcher: launcher?,
+            icon,
+            profile,
+        })
+    }
+
+    fn write_manifest(&self) -> Result<()> {
+        let mut manifest = format!(
+            "name={}\ndeleted={}\nlauncher={}\n",
+            self.name,
+            self.deleted,
+            self.launcher.display()
+        );
+
+        if let Some(icon) = &self.icon {
+            manifest.push_str(&format!("icon={}\n", icon.display()));
+        }
+
+        if let Some(profile) = &self.profile {
+            manifest.push_str(&format!("profile={}\n", profile.display()));
+        }
+
+        write_atomic(&self.path.join("manifest"), manifest.as_bytes())
+    }
+
+    /// Original location and location in the trash of the launcher, icon and profile.
+    fn items(&self) -> Vec<(PathBuf, PathBuf)> {
+        let mut items = vec![(self.launcher.clone(), self.path.join("launcher.desktop"))];
+        items.extend(
+            self.icon
+                .iter()
+                .map(|icon| (icon.clone(), self.path.join("icon"))),
+        );
+        items.extend(
+            self.profile
+                .iter()
+                .map(|profile| (profile.clone(), self.path.join("profile"))),
+        );
+        items
+    }
+
+    /// Puts the launcher, icon and profile back where they were deleted from.
+    pub fn restore(&self) -> Result<()> {
+        let items = self.items();
+
+        if let Some((target, _)) = items.iter().find(|(target, _)| target.exists()) {
+            return Err(anyhow!("{} already exists", target.display()));
+        }
+
+        let moves: Vec<(PathBuf, PathBuf)> = items
+            .into_iter()
+            .map(|(original, trashed)| (trashed, original))
+            .collect();
+
+        move_all(&moves)?;
+        remove_dir_all(&self.path)?;
+
+        tracing::info!("Restored {} from trash.", self.name);
+
+        Ok(())
+    }
+
+    pub fn purge(&self) -> Result<()> {
+        remove_dir_all(&self.path)?;
+        tracing::info!("Purged {} from trash.", self.name);
+
+        Ok(())
+    }
+}
+
+/// Moves a web app to the trash. The profile stays in place with `keep_profile`.
+pub fn trash(launcher: &WebAppLauncher, keep_profile: bool) -> Result<TrashEntry> {
+    let deleted = now();
+
+    let profile = if keep_profile {
+        None
+    } else {
+        launcher.removable_profile()
+    };
+
+    let entry = TrashEntry {
+        path: trash_location().join(format!("{}-{}", launcher.codename, deleted)),
+        name: launcher.name.clone(),
+        deleted,
+        launcher: launcher.path.clone(),
+        icon: launcher.removable_icon(),
+        profile,
+    };
+
+    create_dir_all(&entry.path)?;
+
+    // an entry that is only partly in the trash could neither be restored nor be
+    // launched, so nothing of it is left there
+    if let Err(e) = entry
+        .write_manifest()
+        .and_then(|()| move_all(&entry.items()))
+    {
+        // whatever could not be moved back is still reachable through the entry
+        let moved_back = entry
+            .items()
+            .iter()
+            .all(|(original, trashed)| original.exists() && !trashed.exists());
+
+        if moved_back {
+            if let Err(e) = remove_dir_all(&entry.path) {
+                tracing::error!("cant remove trash entry {:?}: {}", entry.path, e);
+            }
+        }
+
+        return Err(e);
+    }
+
+    tracing::info!("Moved {} to trash.", entry.name);
+
+    Ok(entry)
+}
+
+/// Trashed web apps, most recently deleted first.
+pub fn trash_entries() -> Vec<TrashEntry> {
+    let mut entries: Vec<TrashEntry> = match fs::read_dir(trash_location()) {
+        Ok(dirs) => dirs
+            .flatten()
+            .filter_map(|dir| TrashEntry::read(dir.path()))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    entries.sort_by_key(|entry| std::cmp::Reverse(entry.deleted));
+    entries
+}
+
+/// Purges the web apps deleted more than [`TRASH_EXPIRY_DAYS`] ago.
+pub fn purge_expired() -> Result<()> {
+    let now = now();
+
+    trash_entries()
+        .iter()
+        .filter(|entry| now.saturating_sub(entry.deleted) > TRASH_EXPIRY_DAYS * 86400)
+        .try_for_each(TrashEntry::purge)
+}
+
+/// Moves every `(from, to)` pair. When one of them fails, the ones already moved
+/// are moved back, so either all or none of them end up at their target.
+fn move_all(moves: &[(PathBuf, PathBuf)]) -> Result<()> {
+    for (idx, (from, to)) in moves.iter().enumerate() {
+        if let Err(e) = move_path(from, to) {
+            for (from, to) in moves[..idx].iter().rev() {
+                if let Err(e) = move_path(to, from) {
+                    tracing::error!("cant move {:?} back: {}", from, e);
+                }
+            }
+
+            return Err(e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Renames `from` to `to`, copying when both are on different filesystems.
+/// A partial copy is removed again, `from` stays untouched in that case.
+fn move_path(from: &Path, to: &Path) -> Result<()> {
+    if let Some(parent) = to.parent() {
+        create_dir_all(parent)?;
+    }
+
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+
+    if let Err(e) = copy_path(from, to) {
+        let removed = if to.is_dir() {
+            remove_dir_all(to)
+        } else if to.exists() {
+            fs::remove_file(to)
+        } else {
+            Ok(())
+        };
+
+        if let Err(e) = removed {
+            tracing::error!("cant remove partial copy {:?}: {}", to, e);
+        }
+
+        return Err(e);
+    }
+
+    if from.is_dir() {
+        remove_dir_all(from)?;
+    } else {
+        fs::remove_file(from)?;
+    }
+
+    Ok(())
+}
+
+fn copy_path(from: &Path, to: &Path) -> Result<()> {
+    if from.is_dir() {
+        for entry in WalkDir::new(from) {
+            let entry = entry?;
+            let target = to.join(entry.path().strip_prefix(from)?);
+
+            if entry.file_type().is_dir() {
+                create_dir_all(&target)?;
+            } else if entry.file_type().is_file() {
+                fs::copy(entry.path(), &target)?;
+            }
+        }
+    } else {
+        fs::copy(from, to)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_moves_are_rolled_back() {
+        let dir = std::env::temp_dir().join(format!("webapps-trash-{}", std::process::id()));
+        let _ = remove_dir_all(&dir);
+        create_dir_all(dir.join("profile")).unwrap();
+        fs::write(dir.join("launcher.desktop"), "[Desktop Entry]").unwrap();
+        fs::write(dir.join("profile/prefs.js"), "").unwrap();
+
+        let moves = vec![
+            (
+                dir.join("launcher.desktop"),
+                dir.join("trash/launcher.desktop"),
+            ),
+            (dir.join("profile"), dir.join("trash/profile")),
+            (dir.join("missing"), dir.join("trash/icon")),
+        ];
+
+        assert!(move_all(&moves).is_err());
+        assert!(dir.join("launcher.desktop").exists());
+        assert!(dir.join("profile/prefs.js").exists());
+        assert!(!dir.join("trash/launcher.desktop").exists());
+        assert!(!dir.join("trash/profile").exists());
+
+        remove_dir_all(dir).unwrap();
+    }
+}
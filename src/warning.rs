@@ -9,6 +9,10 @@ pub enum WarnMessages {
     AppUrl,
     AppIcon,
     AppBrowser,
+    WindowSize,
+    WindowPosition,
+    EnvVarName,
+    SaveFailed,
 }
 
 #[derive(Debug, Clone)]
@@ -29,6 +33,12 @@ impl std::fmt::Display for WarnMessages {
             WarnMessages::AppBrowser => {
                 write!(f, "{}", fl!("warning", "app-browser"))
             }
+            WarnMessages::WindowSize => write!(f, "{}", fl!("warning", "window-size")),
+            WarnMessages::WindowPosition => {
+                write!(f, "{}", fl!("warning", "window-position"))
+            }
+            WarnMessages::EnvVarName => write!(f, "{}", fl!("warning", "env-var-name")),
+            WarnMessages::SaveFailed => write!(f, "{}", fl!("warning", "save-failed")),
         }
     }
 }